@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 1, "Not Quite Lisp");
+
 pub fn part1(input: &str) -> impl Display {
     let mut floor = 0;
     for c in input.chars() {
@@ -41,12 +43,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "138");
+        util::check_answer(YEAR, DAY, 1, part1(&input));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1771");
+        util::check_answer(YEAR, DAY, 2, part2(&input));
     }
 }
@@ -1,18 +1,24 @@
 use crate::prelude::*;
 
-fn parse_input(input: &str) -> impl Iterator<Item = (u32, u32, u32)> + '_ {
-    input.lines().map(|line| {
-        let mut split = line.split('x');
-        let l = split.next().unwrap().parse().unwrap();
-        let w = split.next().unwrap().parse().unwrap();
-        let h = split.next().unwrap().parse().unwrap();
-        (l, w, h)
-    })
+crate::solution!(2015, 2, "I Was Told There Would Be No Math", parse_input -> Vec<(u32, u32, u32)>);
+
+fn parse_input(input: &str) -> Vec<(u32, u32, u32)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut split = line.split('x');
+            let l = split.next().unwrap().parse().unwrap();
+            let w = split.next().unwrap().parse().unwrap();
+            let h = split.next().unwrap().parse().unwrap();
+            (l, w, h)
+        })
+        .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    parse_input(input)
-        .map(|(l, w, h)| {
+pub fn part1(presents: &[(u32, u32, u32)]) -> impl Display {
+    presents
+        .iter()
+        .map(|&(l, w, h)| {
             let lw = l * w;
             let wh = w * h;
             let hl = h * l;
@@ -21,9 +27,10 @@ pub fn part1(input: &str) -> impl Display {
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    parse_input(input)
-        .map(|(l, w, h)| {
+pub fn part2(presents: &[(u32, u32, u32)]) -> impl Display {
+    presents
+        .iter()
+        .map(|&(l, w, h)| {
             let mut sorted = [l, w, h];
             sorted.sort();
             l * w * h + 2 * sorted[0] + 2 * sorted[1]
@@ -44,23 +51,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "58");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "58");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1588178");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "34");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "34");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "3783758");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,15 +1,17 @@
 use crate::prelude::*;
 
-fn parse_input(input: &str) -> impl Iterator<Item = char> + '_ {
-    input.trim().chars()
+crate::solution!(2015, 3, "Perfectly Spherical Houses in a Vacuum", parse_input -> &'a str);
+
+fn parse_input(input: &str) -> &str {
+    input.trim()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let mut seen = HashSet::default();
+pub fn part1(moves: &&str) -> impl Display {
+    let mut presents = SparseGrid::new(0u32);
     let mut position = Point::new(0, 0);
-    seen.insert(position);
+    presents[position] += 1;
 
-    for c in parse_input(input) {
+    for c in moves.chars() {
         match c {
             '>' => position.x += 1,
             '<' => position.x -= 1,
@@ -17,18 +19,18 @@ pub fn part1(input: &str) -> impl Display {
             'v' => position.y -= 1,
             _ => {}
         }
-        seen.insert(position);
+        presents[position] += 1;
     }
-    seen.len()
+    presents.len()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let mut seen = HashSet::default();
+pub fn part2(moves: &&str) -> impl Display {
+    let mut presents = SparseGrid::new(0u32);
     let mut santa_pos = Point::new(0, 0);
     let mut robot_pos = Point::new(0, 0);
-    seen.insert(santa_pos);
+    presents[santa_pos] += 2;
 
-    for (i, c) in parse_input(input).enumerate() {
+    for (i, c) in moves.chars().enumerate() {
         let position = if i % 2 == 0 {
             &mut santa_pos
         } else {
@@ -41,9 +43,9 @@ pub fn part2(input: &str) -> impl Display {
             'v' => position.y -= 1,
             _ => {}
         }
-        seen.insert(*position);
+        presents[*position] += 1;
     }
-    seen.len()
+    presents.len()
 }
 
 #[cfg(test)]
@@ -59,23 +61,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2081");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "11");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "11");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "2341");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -2,6 +2,12 @@ use md5::Digest;
 
 use crate::prelude::*;
 
+crate::solution!(2015, 4, "The Ideal Stocking Stuffer", parse_input -> &'a str);
+
+fn parse_input(input: &str) -> &str {
+    input.trim()
+}
+
 fn search(input: &str, part2: bool) -> usize {
     let buf = String::with_capacity(input.len() + 10);
 
@@ -21,12 +27,12 @@ fn search(input: &str, part2: bool) -> usize {
         .unwrap()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    search(input.trim(), false)
+pub fn part1(key: &str) -> impl Display {
+    search(key, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    search(input.trim(), true)
+pub fn part2(key: &str) -> impl Display {
+    search(key, true)
 }
 
 #[cfg(test)]
@@ -41,12 +47,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string().parse::<u32>().unwrap(), 346_386);
+        util::check_answer(YEAR, DAY, 1, part1(parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string().parse::<u32>().unwrap(), 9_958_218);
+        util::check_answer(YEAR, DAY, 2, part2(parse_input(&input)));
     }
 }
@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
-fn parse_input(input: &str) -> impl Iterator<Item = &str> + '_ {
-    input.lines()
+crate::solution!(2015, 5, "Doesn't He Have Intern-Elves For This?", parse_input -> Vec<&'a str>);
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
 }
 
 fn has_three_vowels(s: &&str) -> bool {
@@ -32,16 +34,20 @@ fn one_letter_gap_pair(s: &&str) -> bool {
     s.bytes().tuple_windows().any(|(a, _, b)| a == b)
 }
 
-pub fn part1(input: &str) -> impl Display {
-    parse_input(input)
+pub fn part1(strings: &[&str]) -> impl Display {
+    strings
+        .iter()
+        .copied()
         .filter(has_three_vowels)
         .filter(has_one_letter_twice)
         .filter(does_not_contain_invalid_str)
         .count()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    parse_input(input)
+pub fn part2(strings: &[&str]) -> impl Display {
+    strings
+        .iter()
+        .copied()
         .filter(contains_pair_twice)
         .filter(one_letter_gap_pair)
         .count()
@@ -66,13 +72,13 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE1).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE1)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "258");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     const EXAMPLE2: &str = indoc! {"
@@ -84,12 +90,12 @@ mod tests {
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE2).to_string(), "2");
+        assert_eq!(part2(&parse_input(EXAMPLE2)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "53");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
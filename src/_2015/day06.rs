@@ -1,42 +1,47 @@
 use crate::prelude::*;
 
-#[derive(Debug)]
+crate::solution!(2015, 6, "Probably a Fire Hazard", parse_input -> Vec<Instruction>);
+
+#[derive(Debug, Clone, Copy)]
 enum Op {
     TurnOn,
     TurnOff,
     Toggle,
 }
 
-struct Instruction {
+pub struct Instruction {
     op: Op,
     start: Point<usize>,
     end: Point<usize>,
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = Instruction> + '_ {
+fn parse_input(input: &str) -> Vec<Instruction> {
     let re = Regex::new(r"(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)").unwrap();
-    input.lines().map(move |line| {
-        let c = re.captures(line).unwrap();
-        Instruction {
-            op: match &c[1] {
-                "turn on" => Op::TurnOn,
-                "turn off" => Op::TurnOff,
-                "toggle" => Op::Toggle,
-                _ => panic!(),
-            },
-            start: Point::new(c[2].parse().unwrap(), c[3].parse().unwrap()),
-            // Add 1 to make this an exclusive range, makes the later code nicer
-            end: Point::new(
-                c[4].parse::<usize>().unwrap() + 1,
-                c[5].parse::<usize>().unwrap() + 1,
-            ),
-        }
-    })
+    input
+        .lines()
+        .map(|line| {
+            let c = re.captures(line).unwrap();
+            Instruction {
+                op: match &c[1] {
+                    "turn on" => Op::TurnOn,
+                    "turn off" => Op::TurnOff,
+                    "toggle" => Op::Toggle,
+                    _ => panic!(),
+                },
+                start: Point::new(c[2].parse().unwrap(), c[3].parse().unwrap()),
+                // Add 1 to make this an exclusive range, makes the later code nicer
+                end: Point::new(
+                    c[4].parse::<usize>().unwrap() + 1,
+                    c[5].parse::<usize>().unwrap() + 1,
+                ),
+            }
+        })
+        .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(instructions: &[Instruction]) -> impl Display {
     let mut grid: Grid<bool> = Grid::new(1000, 1000);
-    for Instruction { op, start, end } in parse_input(input) {
+    for &Instruction { op, start, end } in instructions {
         match op {
             Op::TurnOn => grid.fill(start, end, true),
             Op::TurnOff => grid.fill(start, end, false),
@@ -52,9 +57,9 @@ pub fn part1(input: &str) -> impl Display {
     grid.iter().filter(|(&b, _)| b).count()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(instructions: &[Instruction]) -> impl Display {
     let mut grid: Grid<i16> = Grid::new(1000, 1000);
-    for Instruction { op, start, end } in parse_input(input) {
+    for &Instruction { op, start, end } in instructions {
         let val = match op {
             Op::TurnOn => 1,
             Op::TurnOff => -1,
@@ -82,12 +87,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "543903");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "14687245");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
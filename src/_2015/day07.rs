@@ -2,7 +2,9 @@ use std::ops::{Shl, Shr};
 
 use crate::prelude::*;
 
-type Map = HashMap<String, (Gate, Option<u16>)>;
+crate::solution!(2015, 7, "Some Assembly Required", parse_input -> Map);
+
+pub type Map = HashMap<String, (Gate, Option<u16>)>;
 
 fn eval_gate(gates: &mut Map, key: &str) -> u16 {
     let (gate, cached) = gates.get(key).cloned().unwrap();
@@ -17,7 +19,7 @@ fn eval_gate(gates: &mut Map, key: &str) -> u16 {
 }
 
 #[derive(Debug, Clone)]
-enum SignalOrGate {
+pub enum SignalOrGate {
     Signal(u16),
     Gate(String),
 }
@@ -40,7 +42,7 @@ impl SignalOrGate {
 }
 
 #[derive(Debug, Clone)]
-enum Gate {
+pub enum Gate {
     Value { v: SignalOrGate },
     And { a: SignalOrGate, b: SignalOrGate },
     Or { a: SignalOrGate, b: SignalOrGate },
@@ -62,49 +64,52 @@ impl Gate {
     }
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = (String, Gate)> + '_ {
-    input.lines().map(|line| {
-        let (left, right) = line.split_once(" -> ").unwrap();
-
-        let gate = if let Some((a, b)) = left.split_once(" AND ") {
-            Gate::And {
-                a: SignalOrGate::parse(a),
-                b: SignalOrGate::parse(b),
-            }
-        } else if let Some((a, b)) = left.split_once(" OR ") {
-            Gate::Or {
-                a: SignalOrGate::parse(a),
-                b: SignalOrGate::parse(b),
-            }
-        } else if let Some((a, b)) = left.split_once(" LSHIFT ") {
-            Gate::Lshift {
-                a: a.into(),
-                b: b.parse().unwrap(),
-            }
-        } else if let Some((a, b)) = left.split_once(" RSHIFT ") {
-            Gate::Rshift {
-                a: a.into(),
-                b: b.parse().unwrap(),
-            }
-        } else if let Some(a) = left.strip_prefix("NOT ") {
-            Gate::Not { a: a.into() }
-        } else {
-            Gate::Value {
-                v: SignalOrGate::parse(left),
-            }
-        };
-
-        (right.into(), gate)
-    })
+fn parse_input(input: &str) -> Map {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_once(" -> ").unwrap();
+
+            let gate = if let Some((a, b)) = left.split_once(" AND ") {
+                Gate::And {
+                    a: SignalOrGate::parse(a),
+                    b: SignalOrGate::parse(b),
+                }
+            } else if let Some((a, b)) = left.split_once(" OR ") {
+                Gate::Or {
+                    a: SignalOrGate::parse(a),
+                    b: SignalOrGate::parse(b),
+                }
+            } else if let Some((a, b)) = left.split_once(" LSHIFT ") {
+                Gate::Lshift {
+                    a: a.into(),
+                    b: b.parse().unwrap(),
+                }
+            } else if let Some((a, b)) = left.split_once(" RSHIFT ") {
+                Gate::Rshift {
+                    a: a.into(),
+                    b: b.parse().unwrap(),
+                }
+            } else if let Some(a) = left.strip_prefix("NOT ") {
+                Gate::Not { a: a.into() }
+            } else {
+                Gate::Value {
+                    v: SignalOrGate::parse(left),
+                }
+            };
+
+            (right.into(), (gate, None))
+        })
+        .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let mut gates: Map = Map::from_iter(parse_input(input).map(|(k, v)| (k, (v, None))));
+pub fn part1(gates: &Map) -> impl Display {
+    let mut gates = gates.clone();
     eval_gate(&mut gates, "a")
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let mut gates: Map = Map::from_iter(parse_input(input).map(|(k, v)| (k, (v, None))));
+pub fn part2(gates: &Map) -> impl Display {
+    let mut gates = gates.clone();
     let mut gate_clone = gates.clone();
     let a = eval_gate(&mut gates, "a");
     gate_clone.insert(
@@ -131,12 +136,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "16076");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "2797");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
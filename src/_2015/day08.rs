@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use crate::prelude::*;
 
+crate::solution!(2015, 8, "Matchsticks");
+
 pub fn part1(input: &str) -> impl Display {
     let mut mem_size = 0;
     let mut code_size = 0;
@@ -67,7 +69,7 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1342");
+        util::check_answer(YEAR, DAY, 1, part1(&input));
     }
 
     #[test]
@@ -78,6 +80,6 @@ mod tests {
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "2074");
+        util::check_answer(YEAR, DAY, 2, part2(&input));
     }
 }
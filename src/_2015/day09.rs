@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 9, "All in a Single Night", parse_input -> Vec<Route>);
+
 #[derive(Debug, PartialEq)]
-struct Route {
+pub struct Route {
     start: String,
     end: String,
     distance: u32,
@@ -34,13 +36,11 @@ struct Output {
     max_dist: u32,
 }
 
-fn solve(input: &str) -> Output {
-    let routes = parse_input(input);
-
+fn solve(routes: &[Route]) -> Output {
     let mut route_map = HashMap::default();
     let mut locations = HashSet::default();
 
-    for r in &routes {
+    for r in routes {
         locations.insert(r.start.clone());
         locations.insert(r.end.clone());
 
@@ -88,12 +88,12 @@ fn solve(input: &str) -> Output {
     Output { min_dist, max_dist }
 }
 
-pub fn part1(input: &str) -> impl Display {
-    solve(input).min_dist
+pub fn part1(routes: &[Route]) -> impl Display {
+    solve(routes).min_dist
 }
 
-pub fn part2(input: &str) -> impl Display {
-    solve(input).max_dist
+pub fn part2(routes: &[Route]) -> impl Display {
+    solve(routes).max_dist
 }
 
 #[cfg(test)]
@@ -113,23 +113,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "605");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "605");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "207");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "982");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "982");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "804");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 10, "Elves Look, Elves Say", parse_input -> Vec<u32>);
+
 fn parse_input(input: &str) -> Vec<u32> {
     input
         .trim()
@@ -32,14 +34,12 @@ fn look_and_say(arr: Vec<u32>, n: usize) -> usize {
     prev.len()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let arr = parse_input(input);
-    look_and_say(arr, 40)
+pub fn part1(digits: &[u32]) -> impl Display {
+    look_and_say(digits.to_vec(), 40)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let arr = parse_input(input);
-    look_and_say(arr, 50)
+pub fn part2(digits: &[u32]) -> impl Display {
+    look_and_say(digits.to_vec(), 50)
 }
 
 #[cfg(test)]
@@ -54,12 +54,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "252594");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "3579328");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,11 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 11, "Corporate Policy", parse_input -> &'a str);
+
+fn parse_input(input: &str) -> &str {
+    input.trim()
+}
+
 fn three_increasing_chars(s: &[char]) -> bool {
     s.iter()
         .copied()
@@ -51,12 +57,12 @@ fn next_password(password: &str) -> String {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
-    next_password(input.trim())
+pub fn part1(password: &str) -> impl Display {
+    next_password(password)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    next_password(&next_password(input.trim()))
+pub fn part2(password: &str) -> impl Display {
+    next_password(&next_password(password))
 }
 
 #[cfg(test)]
@@ -70,19 +76,19 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1("abcdefgh").to_string(), "abcdffaa");
-        assert_eq!(part1("ghijklmn").to_string(), "ghjaabcc");
+        assert_eq!(part1(parse_input("abcdefgh")).to_string(), "abcdffaa");
+        assert_eq!(part1(parse_input("ghijklmn")).to_string(), "ghjaabcc");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "hepxxyzz");
+        util::check_answer(YEAR, DAY, 1, part1(parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "heqaabcc");
+        util::check_answer(YEAR, DAY, 2, part2(parse_input(&input)));
     }
 }
@@ -2,6 +2,12 @@ use serde_json::Value;
 
 use crate::prelude::*;
 
+crate::solution!(2015, 12, "JSAbacusFramework.io", parse_input -> Value);
+
+fn parse_input(input: &str) -> Value {
+    serde_json::from_str(input).unwrap()
+}
+
 fn sum_value(value: &Value, ignore_red: bool) -> i64 {
     match value {
         Value::Null => 0,
@@ -19,14 +25,12 @@ fn sum_value(value: &Value, ignore_red: bool) -> i64 {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let value = serde_json::from_str::<serde_json::Value>(input).unwrap();
-    sum_value(&value, false)
+pub fn part1(value: &Value) -> impl Display {
+    sum_value(value, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let value = serde_json::from_str::<serde_json::Value>(input).unwrap();
-    sum_value(&value, true)
+pub fn part2(value: &Value) -> impl Display {
+    sum_value(value, true)
 }
 
 #[cfg(test)]
@@ -41,12 +45,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "119433");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "68466");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
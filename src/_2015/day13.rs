@@ -1,34 +1,39 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 13, "Knights of the Dinner Table", parse_input -> Vec<Entry>);
+
 #[derive(Debug)]
-struct Entry {
+pub struct Entry {
     person: String,
     target: String,
     value: i32,
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = Entry> + '_ {
+fn parse_input(input: &str) -> Vec<Entry> {
     let re = Regex::new(r"(\w+) would (gain|lose) (\d+) happiness units by sitting next to (\w+).")
         .unwrap();
-    input.lines().map(move |line| {
-        let c = re.captures(line).unwrap();
-        Entry {
-            person: c[1].into(),
-            target: c[4].into(),
-            value: if &c[2] == "gain" { 1 } else { -1 } * c[3].parse::<i32>().unwrap(),
-        }
-    })
+    input
+        .lines()
+        .map(|line| {
+            let c = re.captures(line).unwrap();
+            Entry {
+                person: c[1].into(),
+                target: c[4].into(),
+                value: if &c[2] == "gain" { 1 } else { -1 } * c[3].parse::<i32>().unwrap(),
+            }
+        })
+        .collect()
 }
 
-fn solve(input: &str, include_self: bool) -> i32 {
+fn solve(entries: &[Entry], include_self: bool) -> i32 {
     let mut people = Vec::new();
     let mut opinion_map = HashMap::default();
 
-    for entry in parse_input(input) {
+    for entry in entries {
         if let Err(idx) = people.binary_search(&entry.person) {
             people.insert(idx, entry.person.clone());
         }
-        opinion_map.insert((entry.person, entry.target), entry.value);
+        opinion_map.insert((entry.person.clone(), entry.target.clone()), entry.value);
     }
 
     if include_self {
@@ -55,12 +60,12 @@ fn solve(input: &str, include_self: bool) -> i32 {
         .unwrap()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    solve(input, false)
+pub fn part1(entries: &[Entry]) -> impl Display {
+    solve(entries, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    solve(input, true)
+pub fn part2(entries: &[Entry]) -> impl Display {
+    solve(entries, true)
 }
 
 #[cfg(test)]
@@ -89,23 +94,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "330");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "330");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "733");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "286");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "286");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "725");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
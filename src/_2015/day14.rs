@@ -1,13 +1,15 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 14, "Reindeer Olympics", parse_input -> Vec<ReindeerStats>);
+
 #[derive(Debug, Clone, Copy)]
 enum State {
     Running(u32),
     Resting(u32),
 }
 
-#[derive(Debug)]
-struct ReindeerStats {
+#[derive(Debug, Clone)]
+pub struct ReindeerStats {
     _name: String,
     speed: u32,
     run_duration: u32,
@@ -18,24 +20,27 @@ struct ReindeerStats {
     points: u32,
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = ReindeerStats> + '_ {
+fn parse_input(input: &str) -> Vec<ReindeerStats> {
     let re = Regex::new(
         r"(\w+) can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds.",
     )
     .unwrap();
-    input.lines().map(move |line| {
-        let c = re.captures(line).unwrap();
-        ReindeerStats {
-            _name: c[1].to_owned(),
-            speed: c[2].parse().unwrap(),
-            run_duration: c[3].parse().unwrap(),
-            rest_duration: c[4].parse().unwrap(),
-
-            position: 0,
-            state: State::Running(c[3].parse().unwrap()),
-            points: 0,
-        }
-    })
+    input
+        .lines()
+        .map(|line| {
+            let c = re.captures(line).unwrap();
+            ReindeerStats {
+                _name: c[1].to_owned(),
+                speed: c[2].parse().unwrap(),
+                run_duration: c[3].parse().unwrap(),
+                rest_duration: c[4].parse().unwrap(),
+
+                position: 0,
+                state: State::Running(c[3].parse().unwrap()),
+                points: 0,
+            }
+        })
+        .collect()
 }
 
 fn run(reindeer: &mut Vec<ReindeerStats>, seconds: usize) {
@@ -72,14 +77,14 @@ fn run(reindeer: &mut Vec<ReindeerStats>, seconds: usize) {
     println!("{reindeer:?}");
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let mut reindeer = parse_input(input).collect::<Vec<_>>();
+pub fn part1(reindeer: &[ReindeerStats]) -> impl Display {
+    let mut reindeer = reindeer.to_vec();
     run(&mut reindeer, 2503);
     reindeer.iter().map(|r| r.position).max().unwrap()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let mut reindeer = parse_input(input).collect::<Vec<_>>();
+pub fn part2(reindeer: &[ReindeerStats]) -> impl Display {
+    let mut reindeer = reindeer.to_vec();
     run(&mut reindeer, 2503);
     reindeer.iter().map(|r| r.points).max().unwrap()
 }
@@ -100,7 +105,7 @@ mod tests {
 
     #[test]
     fn example() {
-        let mut reindeer = parse_input(EXAMPLE).collect::<Vec<_>>();
+        let mut reindeer = parse_input(EXAMPLE);
         run(&mut reindeer, 1000);
         let dancer = reindeer.iter().find(|r| r._name == "Dancer").unwrap();
         let comet = reindeer.iter().find(|r| r._name == "Comet").unwrap();
@@ -113,12 +118,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2655");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1059");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
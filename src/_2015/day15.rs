@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 15, "Science for Hungry People", parse_input -> Vec<Ingredient>);
+
 #[derive(Debug)]
-struct Ingredient {
+pub struct Ingredient {
     _name: String,
     capacity: i64,
     durability: i64,
@@ -10,28 +12,31 @@ struct Ingredient {
     calories: i64,
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = Ingredient> + '_ {
+fn parse_input(input: &str) -> Vec<Ingredient> {
     let re = Regex::new(
         r"(\w+): capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (-?\d+)",
     ).unwrap();
-    input.lines().map(move |line| {
-        let c = re.captures(line).unwrap();
-        Ingredient {
-            _name: c[1].to_owned(),
-            capacity: c[2].parse().unwrap(),
-            durability: c[3].parse().unwrap(),
-            flavor: c[4].parse().unwrap(),
-            texture: c[5].parse().unwrap(),
-            calories: c[6].parse().unwrap(),
-        }
-    })
+    input
+        .lines()
+        .map(|line| {
+            let c = re.captures(line).unwrap();
+            Ingredient {
+                _name: c[1].to_owned(),
+                capacity: c[2].parse().unwrap(),
+                durability: c[3].parse().unwrap(),
+                flavor: c[4].parse().unwrap(),
+                texture: c[5].parse().unwrap(),
+                calories: c[6].parse().unwrap(),
+            }
+        })
+        .collect()
 }
 
 fn nested_loops(
     depth: usize,
     remaining: i64,
     current: &mut Vec<i64>,
-    ingredients: &Vec<Ingredient>,
+    ingredients: &[Ingredient],
     calorie_requirement: bool,
 ) -> i64 {
     if depth == 0 {
@@ -73,14 +78,12 @@ fn nested_loops(
         .unwrap_or(i64::MIN)
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let ingredients = parse_input(input).collect::<Vec<_>>();
-    nested_loops(4, 100, &mut Vec::new(), &ingredients, false)
+pub fn part1(ingredients: &[Ingredient]) -> impl Display {
+    nested_loops(4, 100, &mut Vec::new(), ingredients, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let ingredients = parse_input(input).collect::<Vec<_>>();
-    nested_loops(4, 100, &mut Vec::new(), &ingredients, true)
+pub fn part2(ingredients: &[Ingredient]) -> impl Display {
+    nested_loops(4, 100, &mut Vec::new(), ingredients, true)
 }
 
 #[cfg(test)]
@@ -99,23 +102,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "62842880");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "62842880");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "21367368");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "57600000");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "57600000");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1766400");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
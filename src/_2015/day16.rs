@@ -2,8 +2,10 @@ use std::cmp::Ordering;
 
 use crate::prelude::*;
 
+crate::solution!(2015, 16, "Aunt Sue", parse_input -> Vec<Sue>);
+
 #[derive(Debug, Default)]
-struct Sue {
+pub struct Sue {
     id: u32,
     items: HashMap<String, u32>,
 }
@@ -24,9 +26,7 @@ fn parse_input(input: &str) -> Vec<Sue> {
         .collect()
 }
 
-fn solve(input: &str, use_ordering: bool) -> impl Display {
-    let sues = parse_input(input);
-
+fn solve(sues: &[Sue], use_ordering: bool) -> impl Display {
     let map: HashMap<_, _> = [
         ("children", (3, Ordering::Equal)),
         ("cats", (7, Ordering::Greater)),
@@ -61,12 +61,12 @@ fn solve(input: &str, use_ordering: bool) -> impl Display {
     panic!("None found");
 }
 
-pub fn part1(input: &str) -> impl Display {
-    solve(input, false)
+pub fn part1(sues: &[Sue]) -> impl Display {
+    solve(sues, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    solve(input, true)
+pub fn part2(sues: &[Sue]) -> impl Display {
+    solve(sues, true)
 }
 
 #[cfg(test)]
@@ -81,12 +81,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "373");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "260");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
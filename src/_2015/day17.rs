@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 17, "No Such Thing as Too Much", parse_input -> Vec<u32>);
+
 fn parse_input(input: &str) -> Vec<u32> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
@@ -26,16 +28,14 @@ fn brute_force(containers: &[u32], target: u32) -> Vec<u32> {
     v
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let containers = parse_input(input);
+pub fn part1(containers: &[u32]) -> impl Display {
     let eggnog = 150;
-    brute_force(&containers, eggnog).iter().sum::<u32>()
+    brute_force(containers, eggnog).iter().sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let containers = parse_input(input);
+pub fn part2(containers: &[u32]) -> impl Display {
     let eggnog = 150;
-    brute_force(&containers, eggnog)
+    brute_force(containers, eggnog)
         .into_iter()
         .find(|&v| v != 0)
         .unwrap()
@@ -60,12 +60,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1638");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "17");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
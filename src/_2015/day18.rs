@@ -1,11 +1,6 @@
 use crate::prelude::*;
 
-#[rustfmt::skip]
-const NEIGHBORS: [(i64, i64); 8] = [
-    (-1, -1), (0, -1), (1, -1),
-    (-1, 0),           (1, 0),
-    (-1, 1),  (0, 1),  (1, 1),
-];
+crate::solution!(2015, 18, "Like a GIF For Your Yard", parse_input -> Grid<u8>);
 
 const ON: u8 = b'#';
 const OFF: u8 = b'.';
@@ -16,16 +11,7 @@ fn parse_input(input: &str) -> Grid<u8> {
 
 fn step(curr: &Grid<u8>, next: &mut Grid<u8>) {
     for (v, point) in curr.iter() {
-        let point_i64: Point<i64> = point.try_into().unwrap();
-        let mut sum = 0;
-        for (dx, dy) in NEIGHBORS {
-            let neighbor_point = Point::new(point_i64.x + dx, point_i64.y + dy);
-            if let Ok(point_usize) = neighbor_point.try_into() {
-                if matches!(curr.get(point_usize), Some(&ON)) {
-                    sum += 1;
-                }
-            }
-        }
+        let sum = curr.neighbors8(point).filter(|(_, &n)| n == ON).count();
         if let Some(p) = next.get_mut(point) {
             let next_value = match (*v == ON, sum) {
                 (true, 2 | 3) => ON,
@@ -56,15 +42,13 @@ fn run(grid: &Grid<u8>, steps: usize, stuck_corners: bool) -> Grid<u8> {
     curr
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let grid = parse_input(input);
-    let end = run(&grid, 100, false);
+pub fn part1(grid: &Grid<u8>) -> impl Display {
+    let end = run(grid, 100, false);
     end.iter().filter(|(&v, _)| v == ON).count()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let grid = parse_input(input);
-    let end = run(&grid, 100, true);
+pub fn part2(grid: &Grid<u8>) -> impl Display {
+    let end = run(grid, 100, true);
     end.iter().filter(|(&v, _)| v == ON).count()
 }
 
@@ -80,12 +64,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1061");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1006");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
-struct Input {
+crate::solution!(2015, 19, "Medicine for Rudolph", parse_input -> Input);
+
+pub struct Input {
     replacements: Vec<(String, String)>,
     molecule: String,
 }
@@ -22,16 +24,16 @@ fn parse_input(input: &str) -> Input {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &Input) -> impl Display {
     let Input {
         replacements,
         molecule,
-    } = parse_input(input);
+    } = input;
 
     let re = Regex::new("[A-Z][a-z]?").unwrap();
     let mut new_molecules = HashSet::default();
 
-    for element in re.find_iter(&molecule) {
+    for element in re.find_iter(molecule) {
         let replacements = replacements
             .iter()
             .filter(|(left, _)| *left == element.as_str())
@@ -47,11 +49,11 @@ pub fn part1(input: &str) -> impl Display {
     new_molecules.len()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &Input) -> impl Display {
     let Input {
         replacements,
         molecule,
-    } = parse_input(input);
+    } = input;
 
     println!("{replacements:?}");
     println!("{molecule}");
@@ -80,23 +82,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "7");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "7");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "509");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "0");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,41 +1,26 @@
 use crate::prelude::*;
+use crate::util::math::{divisor_sum, divisors};
 
-pub fn part1(input: &str) -> impl Display {
-    let n: usize = input.trim().parse().unwrap();
-
-    let r = (1_usize..1_000_000_000)
-        .into_par_iter()
-        .by_exponential_blocks()
-        .find_first(|i| {
-            let mut sum = 0;
-            for j in 1..=*i {
-                if i % j == 0 {
-                    sum += j * 10;
-                }
-            }
-            sum >= n
-        });
-
-    r.unwrap()
+crate::solution!(2015, 20, "Infinite Elves and Infinite Houses", parse_input -> usize);
+
+fn parse_input(input: &str) -> usize {
+    input.trim().parse().unwrap()
+}
+
+pub fn part1(&n: &usize) -> impl Display {
+    // Each elf visits the houses it divides
+    (1_u64..)
+        .find(|&i| divisor_sum(i) as usize * 10 >= n)
+        .unwrap()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let n: usize = input.trim().parse().unwrap();
-
-    let r = (1_usize..1_000_000_000)
-        .into_par_iter()
-        .by_exponential_blocks()
-        .find_first(|i| {
-            let mut sum = 0;
-            for j in 1..=*i {
-                if i % j == 0 && i / j <= 50 {
-                    sum += j * 11;
-                }
-            }
-            sum >= n
-        });
-
-    r.unwrap()
+pub fn part2(&n: &usize) -> impl Display {
+    (1_u64..)
+        .find(|&i| {
+            let sum: u64 = divisors(i).into_iter().filter(|j| i / j <= 50).sum();
+            sum as usize * 11 >= n
+        })
+        .unwrap()
 }
 
 #[cfg(test)]
@@ -47,17 +32,26 @@ mod tests {
     const YEAR: u32 = 2015;
     const DAY: u32 = 20;
 
-    #[ignore = "slow bruteforce"]
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&70).to_string(), "4");
+        assert_eq!(part1(&130).to_string(), "8");
+    }
+
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "665280");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&(11 * 15)).to_string(), "8");
     }
 
-    #[ignore = "slow bruteforce"]
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "705600");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
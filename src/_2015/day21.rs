@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
+crate::solution!(2015, 21, "RPG Simulator 20XX", parse_input -> Hda);
+
 #[derive(Debug, Default)]
-struct Hda {
+pub struct Hda {
     hit_points: u32,
     damage: u32,
     armor: u32,
@@ -21,14 +23,12 @@ fn parse_input(input: &str) -> Hda {
     hda
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let a = parse_input(input);
-    println!("{a:?}");
+pub fn part1(boss: &Hda) -> impl Display {
+    println!("{boss:?}");
     0
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let _ = parse_input(input);
+pub fn part2(_boss: &Hda) -> impl Display {
     0
 }
 
@@ -49,23 +49,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "0");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "0");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,15 +1,17 @@
 use crate::prelude::*;
 
-fn parse_input(input: &str) -> impl Iterator<Item = &str> {
-    input.trim().split(", ")
+crate::solution!(2016, 1, "No Time for a Taxicab", parse_input -> Vec<&'a str>);
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.trim().split(", ").collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(instructions: &[&str]) -> impl Display {
     let start = Point::new(0, 0);
     let mut p = start;
     let mut direction: i32 = 0;
 
-    for m in parse_input(input) {
+    for &m in instructions {
         if &m[0..1] == "R" {
             direction += 1;
         } else {
@@ -28,13 +30,13 @@ pub fn part1(input: &str) -> impl Display {
     p.manhattan_distance(&start)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(instructions: &[&str]) -> impl Display {
     let start: Point<i32> = Point::new(0, 0);
     let mut p = start;
     let mut direction: i32 = 0;
     let mut visited = HashSet::default();
 
-    for m in parse_input(input) {
+    for &m in instructions {
         if &m[0..1] == "R" {
             direction += 1;
         } else {
@@ -69,23 +71,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1("R5, L5, R5, R3").to_string(), "12");
+        assert_eq!(part1(&parse_input("R5, L5, R5, R3")).to_string(), "12");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "253");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2("R8, R4, R4, R8").to_string(), "4");
+        assert_eq!(part2(&parse_input("R8, R4, R4, R8")).to_string(), "4");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "126");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,8 +1,16 @@
-pub fn need_fuel(mass: u32) -> u32 {
+use std::fmt::Display;
+
+crate::solution!(2019, 1, "The Tyranny of the Rocket Equation", parse_input -> Vec<u32>);
+
+fn parse_input(input: &str) -> Vec<u32> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn need_fuel(mass: u32) -> u32 {
     (mass / 3).saturating_sub(2)
 }
 
-pub fn need_fuel_recursive(mass: u32) -> u32 {
+fn need_fuel_recursive(mass: u32) -> u32 {
     let mut total_fuel = 0;
     let mut fuel = need_fuel(mass);
 
@@ -14,20 +22,12 @@ pub fn need_fuel_recursive(mass: u32) -> u32 {
     total_fuel
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    input
-        .lines()
-        .map(|line| line.parse::<u32>().unwrap())
-        .map(need_fuel)
-        .sum::<u32>()
+pub fn part1(masses: &[u32]) -> impl Display {
+    masses.iter().copied().map(need_fuel).sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    input
-        .lines()
-        .map(|line| line.parse::<u32>().unwrap())
-        .map(need_fuel_recursive)
-        .sum::<u32>()
+pub fn part2(masses: &[u32]) -> impl Display {
+    masses.iter().copied().map(need_fuel_recursive).sum::<u32>()
 }
 
 #[cfg(test)]
@@ -41,28 +41,28 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1("12").to_string(), "2");
-        assert_eq!(part1("14").to_string(), "2");
-        assert_eq!(part1("1969").to_string(), "654");
-        assert_eq!(part1("100756").to_string(), "33583");
+        assert_eq!(part1(&parse_input("12")).to_string(), "2");
+        assert_eq!(part1(&parse_input("14")).to_string(), "2");
+        assert_eq!(part1(&parse_input("1969")).to_string(), "654");
+        assert_eq!(part1(&parse_input("100756")).to_string(), "33583");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "3246455");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2("14").to_string(), "2");
-        assert_eq!(part2("1969").to_string(), "966");
-        assert_eq!(part2("100756").to_string(), "50346");
+        assert_eq!(part2(&parse_input("14")).to_string(), "2");
+        assert_eq!(part2(&parse_input("1969")).to_string(), "966");
+        assert_eq!(part2(&parse_input("100756")).to_string(), "50346");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "4866824");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,24 +1,33 @@
+use std::fmt::Display;
+
 use super::intcode::IntcodeI;
 
-fn abc(input: &str, noun: u32, verb: u32) -> Option<u32> {
-    let mut intcode_i = IntcodeI::new(input);
+crate::solution!(2019, 2, "1202 Program Alarm", parse_input -> IntcodeI);
+
+fn parse_input(input: &str) -> IntcodeI {
+    IntcodeI::new(input).unwrap()
+}
 
+fn abc(intcode_i: &mut IntcodeI, noun: i64, verb: i64) -> Option<i64> {
     intcode_i.memory_mut()[1] = noun;
     intcode_i.memory_mut()[2] = verb;
 
-    Some(intcode_i.interpret()?.memory()[0])
+    Some(intcode_i.interpret().ok()?.memory()[0])
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    abc(input, 12, 2).unwrap()
+pub fn part1(program: &IntcodeI) -> impl Display {
+    abc(&mut program.clone(), 12, 2).unwrap()
 }
 
-pub fn part2(input: &str) -> impl ToString {
+pub fn part2(program: &IntcodeI) -> impl Display {
     let expected_output = 19690720;
+    let snapshot = program.snapshot();
+    let mut intcode_i = program.clone();
 
     for noun in 0..100 {
         for verb in 0..100 {
-            let res = abc(input, noun, verb);
+            intcode_i.restore(&snapshot);
+            let res = abc(&mut intcode_i, noun, verb);
             if res == Some(expected_output) {
                 return 100 * noun + verb;
             }
@@ -40,12 +49,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "5434663");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "4559");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,52 +1,43 @@
 use crate::prelude::*;
 
+crate::solution!(2019, 3, "Crossed Wires", parse_line -> (&'a str, &'a str));
+
 fn parse_line(input: &str) -> (&str, &str) {
     let mut lines = input.lines().map(|l| l.trim());
     (lines.next().unwrap(), lines.next().unwrap())
 }
 
-fn trace_line(line: &str, mut on_point: impl FnMut((i32, i32, i32))) {
-    let mut x = 0;
-    let mut y = 0;
+fn trace_line(line: &str, mut on_point: impl FnMut(Point<i64>, i64)) {
+    let mut position = Point::new(0, 0);
     let mut step = 0;
     for inst in line.split(',') {
-        let dir = &inst[0..1];
-        let num = inst[1..].parse::<i32>().unwrap();
-
-        match dir {
-            "R" => (0..num).for_each(|_| {
-                (step, x) = (step + 1, x + 1);
-                on_point((x, y, step))
-            }),
-            "L" => (0..num).for_each(|_| {
-                (step, x) = (step + 1, x - 1);
-                on_point((x, y, step))
-            }),
-            "U" => (0..num).for_each(|_| {
-                (step, y) = (step + 1, y + 1);
-                on_point((x, y, step))
-            }),
-            "D" => (0..num).for_each(|_| {
-                (step, y) = (step + 1, y - 1);
-                on_point((x, y, step))
-            }),
+        let direction = match &inst[0..1] {
+            "R" => Direction::East,
+            "L" => Direction::West,
+            "U" => Direction::North,
+            "D" => Direction::South,
             other => panic!("Unknown dir: {other}"),
         };
+        let num = inst[1..].parse::<i64>().unwrap();
+
+        for _ in 0..num {
+            (step, position) = (step + 1, position + direction.offset());
+            on_point(position, step);
+        }
     }
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let (line0, line1) = parse_line(input);
-    let mut locations = HashSet::default();
-    let mut min_intersection = i32::MAX;
+pub fn part1(&(line0, line1): &(&str, &str)) -> impl Display {
+    let mut wires = SparseGrid::new(false);
+    let mut min_intersection = i64::MAX;
 
-    trace_line(line0, |(x, y, _)| {
-        locations.insert((x, y));
+    trace_line(line0, |point, _| {
+        wires.insert(point, true);
     });
 
-    trace_line(line1, |(x, y, _)| {
-        if locations.contains(&(x, y)) {
-            let dist = x.abs() + y.abs();
+    trace_line(line1, |point, _| {
+        if wires[point] {
+            let dist = point.manhattan_distance(&Point::new(0, 0));
             if dist != 0 {
                 min_intersection = min_intersection.min(dist);
             }
@@ -56,23 +47,22 @@ pub fn part1(input: &str) -> impl ToString {
     min_intersection
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let (line0, line1) = parse_line(input);
-    let mut locations = HashMap::default();
+pub fn part2(&(line0, line1): &(&str, &str)) -> impl Display {
+    let mut wires = SparseGrid::new((0u8, 0));
 
-    trace_line(line0, |(x, y, steps)| {
-        locations.insert((x, y), (1u8, steps));
+    trace_line(line0, |point, steps| {
+        wires.insert(point, (1u8, steps));
     });
 
-    trace_line(line1, |(x, y, steps)| {
-        let a = locations.entry((x, y)).or_insert((0, 0));
+    trace_line(line1, |point, steps| {
+        let a = &mut wires[point];
         a.0 |= 2u8;
         a.1 += steps;
     });
 
-    locations
-        .values()
-        .filter_map(|(a, b)| if *a == 3 { Some(*b) } else { None })
+    wires
+        .iter()
+        .filter_map(|(_, &(a, b))| if a == 3 { Some(b) } else { None })
         .min()
         .unwrap()
 }
@@ -93,23 +83,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "159");
+        assert_eq!(part1(&parse_line(EXAMPLE)).to_string(), "159");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "316");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_line(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "610");
+        assert_eq!(part2(&parse_line(EXAMPLE)).to_string(), "610");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "16368");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_line(&input)));
     }
 }
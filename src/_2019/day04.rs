@@ -1,5 +1,8 @@
+use std::fmt::Display;
 use std::ops::Range;
 
+crate::solution!(2019, 4, "Secure Container", parse_range -> Range<u32>);
+
 fn parse_range(input: &str) -> Range<u32> {
     let (start, end) = input.trim_end().split_once('-').unwrap();
     let start = start.parse::<u32>().unwrap();
@@ -44,16 +47,12 @@ fn is_valid(pass: &u32, strict_adjacent: bool) -> bool {
     has_adj
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    parse_range(input)
-        .filter(|pass| is_valid(pass, false))
-        .count()
+pub fn part1(range: &Range<u32>) -> impl Display {
+    range.clone().filter(|pass| is_valid(pass, false)).count()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    parse_range(input)
-        .filter(|pass| is_valid(pass, true))
-        .count()
+pub fn part2(range: &Range<u32>) -> impl Display {
+    range.clone().filter(|pass| is_valid(pass, true)).count()
 }
 
 #[cfg(test)]
@@ -68,12 +67,12 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "931");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_range(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "609");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_range(&input)));
     }
 }
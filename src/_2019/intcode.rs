@@ -1,109 +1,683 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+pub mod debug;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntcodeError {
+    #[error("{0:?} is not an integer")]
+    Parse(String),
+    #[error("unknown opcode {opcode} at {pc}")]
+    UnknownOpcode { pc: usize, opcode: i64 },
+    #[error("unknown parameter mode {mode} at {pc}")]
+    UnknownMode { pc: usize, mode: i64 },
+    #[error("instruction at {pc} writes to an immediate parameter")]
+    ImmediateWrite { pc: usize },
+    #[error("negative address {address} at {pc}")]
+    NegativeAddress { pc: usize, address: i64 },
+    #[error("instruction at {pc} needs input but none is queued")]
+    NoInput { pc: usize },
+    #[error("arithmetic overflow at {pc}")]
+    Overflow { pc: usize },
+}
+
+/// Why a machine stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// The next instruction reads input but none is queued.
+    NeedsInput,
+    Output(i64),
+    Halted,
+}
+
+/// What executing one instruction did.
+enum Event {
+    Continue,
+    NeedsInput,
+    Output(i64),
+    Halted,
+}
+
+/// A saved copy of a machine's state, see [`IntcodeI::snapshot`].
 #[derive(Debug, Clone)]
+pub struct Snapshot(IntcodeI);
+
+#[derive(Debug, Clone, Default)]
 pub struct IntcodeI {
-    memory: Vec<u32>,
+    memory: Vec<i64>,
     pc: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
 }
 
 impl IntcodeI {
-    pub fn new(input: &str) -> Self {
-        IntcodeI {
-            memory: input
-                .trim()
-                .split(',')
-                .map(|c| c.parse::<u32>().unwrap())
-                .collect(),
-            pc: 0,
+    pub fn new(input: &str) -> Result<Self, IntcodeError> {
+        let memory = input
+            .trim()
+            .split(',')
+            .map(|c| {
+                let c = c.trim();
+                c.parse().map_err(|_| IntcodeError::Parse(c.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(IntcodeI {
+            memory,
+            ..Default::default()
+        })
+    }
+
+    /// Runs until the program halts.
+    pub fn interpret(&mut self) -> Result<&mut Self, IntcodeError> {
+        while self.step()? {}
+        Ok(self)
+    }
+
+    /// Executes one instruction, returning whether the program is still running.
+    ///
+    /// Output is collected to be read with [`IntcodeI::output`].
+    pub fn step(&mut self) -> Result<bool, IntcodeError> {
+        match self.execute()? {
+            Event::Continue => Ok(true),
+            Event::NeedsInput => Err(IntcodeError::NoInput { pc: self.pc }),
+            Event::Output(value) => {
+                self.output.push(value);
+                Ok(true)
+            }
+            Event::Halted => Ok(false),
         }
     }
 
-    pub fn interpret(&mut self) -> Option<&mut Self> {
-        self.pc = 0;
-        while self.step()? {
-            // println!("{self:?}");
+    /// Runs until the program needs input which isn't queued, outputs a value or halts.
+    ///
+    /// Values are returned as they're output rather than collected, and the machine can be
+    /// resumed after queueing more input.
+    pub fn resume(&mut self) -> Result<State, IntcodeError> {
+        loop {
+            match self.execute()? {
+                Event::Continue => {}
+                Event::NeedsInput => return Ok(State::NeedsInput),
+                Event::Output(value) => return Ok(State::Output(value)),
+                Event::Halted => return Ok(State::Halted),
+            }
         }
-        Some(self)
     }
 
-    pub fn step(&mut self) -> Option<bool> {
-        match self.memory[self.pc] {
+    /// Runs on its own thread, reading input from `input` and sending output to `output`.
+    ///
+    /// Input blocks until a value is received, and fails with [`IntcodeError::NoInput`] once
+    /// every sender has been dropped. Output sent after the receiver is dropped is discarded.
+    pub fn spawn(
+        mut self,
+        input: Receiver<i64>,
+        output: Sender<i64>,
+    ) -> JoinHandle<Result<IntcodeI, IntcodeError>> {
+        thread::spawn(move || loop {
+            match self.resume()? {
+                State::NeedsInput => match input.recv() {
+                    Ok(value) => self.push_input(value),
+                    Err(_) => return Err(IntcodeError::NoInput { pc: self.pc }),
+                },
+                State::Output(value) => {
+                    let _ = output.send(value);
+                }
+                State::Halted => return Ok(self),
+            }
+        })
+    }
+
+    fn execute(&mut self) -> Result<Event, IntcodeError> {
+        let pc = self.pc;
+        let instruction = self.read(pc);
+        match instruction % 100 {
             // Add
             1 => {
-                let a = self.param(1)?;
-                let b = self.param(2)?;
-                *self.param_mut(3)? = a + b;
+                let value = self.param(1)?.checked_add(self.param(2)?);
+                let value = value.ok_or(IntcodeError::Overflow { pc })?;
+                self.write_param(3, value)?;
                 self.pc += 4;
             }
             // Mul
             2 => {
-                let a = self.param(1)?;
-                let b = self.param(2)?;
-                *self.param_mut(3)? = a * b;
+                let value = self.param(1)?.checked_mul(self.param(2)?);
+                let value = value.ok_or(IntcodeError::Overflow { pc })?;
+                self.write_param(3, value)?;
                 self.pc += 4;
             }
+            // In
+            3 => {
+                let Some(value) = self.input.pop_front() else {
+                    return Ok(Event::NeedsInput);
+                };
+                self.write_param(1, value)?;
+                self.pc += 2;
+            }
+            // Out
+            4 => {
+                let value = self.param(1)?;
+                self.pc += 2;
+                return Ok(Event::Output(value));
+            }
+            // Jump if true
+            5 => {
+                if self.param(1)? != 0 {
+                    self.pc = self.to_address(self.param(2)?)?;
+                } else {
+                    self.pc += 3;
+                }
+            }
+            // Jump if false
+            6 => {
+                if self.param(1)? == 0 {
+                    self.pc = self.to_address(self.param(2)?)?;
+                } else {
+                    self.pc += 3;
+                }
+            }
+            // Less than
+            7 => {
+                let value = self.param(1)? < self.param(2)?;
+                self.write_param(3, value as i64)?;
+                self.pc += 4;
+            }
+            // Equals
+            8 => {
+                let value = self.param(1)? == self.param(2)?;
+                self.write_param(3, value as i64)?;
+                self.pc += 4;
+            }
+            // Adjust relative base
+            9 => {
+                let base = self.relative_base.checked_add(self.param(1)?);
+                self.relative_base = base.ok_or(IntcodeError::Overflow { pc })?;
+                self.pc += 2;
+            }
             // Hlt
-            99 => {
-                return Some(false);
+            99 => return Ok(Event::Halted),
+            _ => {
+                return Err(IntcodeError::UnknownOpcode {
+                    pc,
+                    opcode: instruction,
+                })
             }
-            op => panic!("Unimplemented opcode {op}"),
         }
 
-        Some(self.pc < self.memory.len())
+        Ok(Event::Continue)
     }
 
-    pub fn memory(&self) -> &[u32] {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.clone())
+    }
+
+    /// Returns to a saved state, reusing this machine's allocations so searches can cheaply
+    /// branch from the same point.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        let saved = &snapshot.0;
+        self.memory.clone_from(&saved.memory);
+        self.pc = saved.pc;
+        self.relative_base = saved.relative_base;
+        self.input.clone_from(&saved.input);
+        self.output.clone_from(&saved.output);
+    }
+
+    pub fn memory(&self) -> &[i64] {
         &self.memory
     }
 
-    pub fn memory_mut(&mut self) -> &mut [u32] {
+    pub fn memory_mut(&mut self) -> &mut [i64] {
         &mut self.memory
     }
 
-    fn param(&self, offset: usize) -> Option<&u32> {
-        let addr = *self.memory.get(self.pc + offset)? as usize;
-        self.memory.get(addr)
+    /// The value at `address`, memory beyond the program is zero.
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    /// Sets the value at `address`, growing memory if needed.
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn extend_input(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.input.extend(values);
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    /// Removes and returns everything output so far.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// The mode of the `n`th parameter of the current instruction.
+    fn mode(&self, n: usize) -> Result<i64, IntcodeError> {
+        let mode = self.read(self.pc) / 10i64.pow(n as u32 + 1) % 10;
+        match mode {
+            0..=2 => Ok(mode),
+            _ => Err(IntcodeError::UnknownMode { pc: self.pc, mode }),
+        }
+    }
+
+    fn to_address(&self, address: i64) -> Result<usize, IntcodeError> {
+        usize::try_from(address).map_err(|_| IntcodeError::NegativeAddress {
+            pc: self.pc,
+            address,
+        })
+    }
+
+    /// The address the `n`th parameter refers to, or `None` in immediate mode.
+    fn param_address(&self, n: usize) -> Result<Option<usize>, IntcodeError> {
+        let raw = self.read(self.pc + n);
+        match self.mode(n)? {
+            0 => self.to_address(raw).map(Some),
+            2 => {
+                let address = self.relative_base.checked_add(raw);
+                let address = address.ok_or(IntcodeError::Overflow { pc: self.pc })?;
+                self.to_address(address).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn param(&self, n: usize) -> Result<i64, IntcodeError> {
+        Ok(match self.param_address(n)? {
+            Some(address) => self.read(address),
+            None => self.read(self.pc + n),
+        })
+    }
+
+    fn write_param(&mut self, n: usize, value: i64) -> Result<(), IntcodeError> {
+        let address = self
+            .param_address(n)?
+            .ok_or(IntcodeError::ImmediateWrite { pc: self.pc })?;
+        self.write(address, value);
+        Ok(())
+    }
+}
+
+/// Runs several machines in turn, passing values between them.
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    machines: Vec<IntcodeI>,
+    halted: Vec<bool>,
+    idle_input: Option<i64>,
+}
+
+impl Scheduler {
+    pub fn new(machines: impl IntoIterator<Item = IntcodeI>) -> Self {
+        let machines = machines.into_iter().collect::<Vec<_>>();
+        Scheduler {
+            halted: vec![false; machines.len()],
+            machines,
+            idle_input: None,
+        }
+    }
+
+    /// Gives a machine `value` when it's run without any queued input, rather than leaving it
+    /// waiting, such as `-1` for machines which poll a network.
+    pub fn with_idle_input(self, value: i64) -> Self {
+        Scheduler {
+            idle_input: Some(value),
+            ..self
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    pub fn machine(&self, i: usize) -> &IntcodeI {
+        &self.machines[i]
+    }
+
+    pub fn machine_mut(&mut self, i: usize) -> &mut IntcodeI {
+        &mut self.machines[i]
+    }
+
+    /// Whether every machine has halted.
+    pub fn is_halted(&self) -> bool {
+        self.halted.iter().all(|&h| h)
+    }
+
+    /// Queues `value` as input for machine `i`.
+    pub fn send(&mut self, i: usize, value: i64) {
+        self.machines[i].push_input(value);
+    }
+
+    /// Runs machine `i` until it halts or needs input, returning everything it output.
+    pub fn run_machine(&mut self, i: usize) -> Result<Vec<i64>, IntcodeError> {
+        let machine = &mut self.machines[i];
+        if self.halted[i] {
+            return Ok(vec![]);
+        }
+        if let Some(idle) = self.idle_input {
+            if machine.input.is_empty() {
+                machine.push_input(idle);
+            }
+        }
+
+        let mut output = vec![];
+        loop {
+            match machine.resume()? {
+                State::NeedsInput => break,
+                State::Output(value) => output.push(value),
+                State::Halted => {
+                    self.halted[i] = true;
+                    break;
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    /// Runs each machine once in turn. `route` is given each machine's index and output, and
+    /// returns the values to send as `(machine, value)`, which are delivered immediately.
+    ///
+    /// Returns whether anything was output.
+    pub fn round(
+        &mut self,
+        mut route: impl FnMut(usize, Vec<i64>) -> Vec<(usize, i64)>,
+    ) -> Result<bool, IntcodeError> {
+        let mut active = false;
+        for i in 0..self.machines.len() {
+            let output = self.run_machine(i)?;
+            if output.is_empty() {
+                continue;
+            }
+            active = true;
+            for (to, value) in route(i, output) {
+                self.send(to, value);
+            }
+        }
+        Ok(active)
     }
 
-    fn param_mut(&mut self, offset: usize) -> Option<&mut u32> {
-        let addr = *self.memory.get(self.pc + offset)? as usize;
-        self.memory.get_mut(addr)
+    /// Sends each machine's output to the next, and the last machine's back to the first when
+    /// `feedback` is set. Runs until every machine has halted or is waiting for input which
+    /// will never come, returning everything the last machine output.
+    pub fn pipeline(&mut self, feedback: bool) -> Result<Vec<i64>, IntcodeError> {
+        let last = self.machines.len().saturating_sub(1);
+        let mut result = vec![];
+        while self.round(|i, output| {
+            if i == last {
+                result.extend(&output);
+                if !feedback {
+                    return vec![];
+                }
+            }
+            let next = (i + 1) % (last + 1);
+            output.into_iter().map(|value| (next, value)).collect()
+        })? {}
+        Ok(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::mpsc;
+
     use super::*;
 
+    fn run(program: &str, input: &[i64]) -> Vec<i64> {
+        let mut intcode_i = IntcodeI::new(program).unwrap();
+        intcode_i.extend_input(input.iter().copied());
+        intcode_i.interpret().unwrap().take_output()
+    }
+
     #[test]
-    fn day1() {
+    fn day2() {
         assert_eq!(
             IntcodeI::new("1,9,10,3,2,3,11,0,99,30,40,50")
+                .unwrap()
                 .interpret()
                 .unwrap()
                 .memory(),
             [3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]
         );
 
-        assert_eq!(
-            IntcodeI::new("1,0,0,0,99").interpret().unwrap().memory(),
-            [2, 0, 0, 0, 99]
-        );
+        let cases: [(&str, &[i64]); 4] = [
+            ("1,0,0,0,99", &[2, 0, 0, 0, 99]),
+            ("2,3,0,3,99", &[2, 3, 0, 6, 99]),
+            ("2,4,4,5,99,0", &[2, 4, 4, 5, 99, 9801]),
+            ("1,1,1,4,99,5,6,0,99", &[30, 1, 1, 4, 2, 5, 6, 0, 99]),
+        ];
+        for (program, memory) in cases {
+            let mut intcode_i = IntcodeI::new(program).unwrap();
+            assert_eq!(intcode_i.interpret().unwrap().memory(), memory);
+        }
+    }
 
-        assert_eq!(
-            IntcodeI::new("2,3,0,3,99").interpret().unwrap().memory(),
-            [2, 3, 0, 6, 99]
-        );
+    #[test]
+    fn day5_modes_and_io() {
+        assert_eq!(run("3,0,4,0,99", &[42]), [42]);
 
-        assert_eq!(
-            IntcodeI::new("2,4,4,5,99,0").interpret().unwrap().memory(),
-            [2, 4, 4, 5, 99, 9801]
-        );
+        let mut intcode_i = IntcodeI::new("1002,4,3,4,33").unwrap();
+        assert_eq!(intcode_i.interpret().unwrap().memory()[4], 99);
+        let mut intcode_i = IntcodeI::new("1101,100,-1,4,0").unwrap();
+        assert_eq!(intcode_i.interpret().unwrap().memory()[4], 99);
+    }
 
+    #[test]
+    fn day5_jumps_and_compares() {
+        let equal_8 = ["3,9,8,9,10,9,4,9,99,-1,8", "3,3,1108,-1,8,3,4,3,99"];
+        let less_8 = ["3,9,7,9,10,9,4,9,99,-1,8", "3,3,1107,-1,8,3,4,3,99"];
+        for input in [7, 8, 9] {
+            for program in equal_8 {
+                assert_eq!(run(program, &[input]), [(input == 8) as i64]);
+            }
+            for program in less_8 {
+                assert_eq!(run(program, &[input]), [(input < 8) as i64]);
+            }
+        }
+
+        let non_zero = [
+            "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+            "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+        ];
+        for program in non_zero {
+            assert_eq!(run(program, &[0]), [0]);
+            assert_eq!(run(program, &[5]), [1]);
+        }
+
+        let compare_8 = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+            1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+            999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+        assert_eq!(run(compare_8, &[7]), [999]);
+        assert_eq!(run(compare_8, &[8]), [1000]);
+        assert_eq!(run(compare_8, &[9]), [1001]);
+    }
+
+    #[test]
+    fn day9_relative_mode_and_large_values() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected = quine
+            .split(',')
+            .map(|c| c.parse().unwrap())
+            .collect::<Vec<i64>>();
+        assert_eq!(run(quine, &[]), expected);
+
+        let output = run("1102,34915192,34915192,7,4,7,99,0", &[]);
+        assert_eq!(output[0].to_string().len(), 16);
+
+        assert_eq!(run("104,1125899906842624,99", &[]), [1125899906842624]);
+        assert_eq!(run("109,-1,203,11,204,11,99", &[7]), [7]);
+    }
+
+    const AMPLIFIER: &str = "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0";
+    const FEEDBACK_AMPLIFIER: &str = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,\
+        4,27,1001,28,-1,28,1005,28,6,99,0,0,5";
+    const ECHO: &str = "3,100,4,100,1105,1,0";
+
+    fn amplifiers(program: &str, phases: [i64; 5]) -> Scheduler {
+        let program = IntcodeI::new(program).unwrap();
+        let mut scheduler = Scheduler::new(phases.map(|phase| {
+            let mut amplifier = program.clone();
+            amplifier.push_input(phase);
+            amplifier
+        }));
+        scheduler.send(0, 0);
+        scheduler
+    }
+
+    #[test]
+    fn resume_on_events() {
+        let mut intcode_i = IntcodeI::new(ECHO).unwrap();
+        assert_eq!(intcode_i.resume(), Ok(State::NeedsInput));
+        assert_eq!(intcode_i.resume(), Ok(State::NeedsInput));
+        intcode_i.extend_input([4, 2]);
+        assert_eq!(intcode_i.resume(), Ok(State::Output(4)));
+        assert_eq!(intcode_i.resume(), Ok(State::Output(2)));
+        assert_eq!(intcode_i.resume(), Ok(State::NeedsInput));
+        assert!(intcode_i.output().is_empty());
+
+        let mut intcode_i = IntcodeI::new("104,1,99").unwrap();
+        assert_eq!(intcode_i.resume(), Ok(State::Output(1)));
+        assert_eq!(intcode_i.resume(), Ok(State::Halted));
+        assert_eq!(intcode_i.resume(), Ok(State::Halted));
+    }
+
+    #[test]
+    fn pipelines() {
+        let mut chain = amplifiers(AMPLIFIER, [4, 3, 2, 1, 0]);
+        assert_eq!(chain.pipeline(false), Ok(vec![43210]));
+        assert!(chain.is_halted());
+
+        let mut feedback = amplifiers(FEEDBACK_AMPLIFIER, [9, 8, 7, 6, 5]);
+        let output = feedback.pipeline(true).unwrap();
+        assert_eq!(output.last(), Some(&139629729));
+        assert!(feedback.is_halted());
+    }
+
+    #[test]
+    fn route_between_machines() {
+        // Each value is passed on to the next machine, one smaller, until it reaches zero
+        let echo = IntcodeI::new(ECHO).unwrap();
+        let mut scheduler = Scheduler::new(vec![echo; 3]);
+        scheduler.send(0, 4);
+        let mut seen = vec![];
+        while scheduler
+            .round(|i, output| {
+                seen.extend(output.iter().map(|&v| (i, v)));
+                output
+                    .into_iter()
+                    .filter(|&v| v > 0)
+                    .map(|v| ((i + 1) % 3, v - 1))
+                    .collect()
+            })
+            .unwrap()
+        {}
+        assert_eq!(seen, [(0, 4), (1, 3), (2, 2), (0, 1), (1, 0)]);
+
+        let echo = IntcodeI::new(ECHO).unwrap();
+        let mut polling = Scheduler::new([echo]).with_idle_input(-1);
+        assert_eq!(polling.run_machine(0), Ok(vec![-1]));
+        polling.send(0, 7);
+        assert_eq!(polling.run_machine(0), Ok(vec![7]));
+    }
+
+    #[test]
+    fn channels() {
+        let program = IntcodeI::new(FEEDBACK_AMPLIFIER).unwrap();
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..5).map(|_| mpsc::channel()).unzip();
+        let (last_tx, last_rx) = mpsc::channel();
+        for (i, phase) in [9, 8, 7, 6, 5].into_iter().enumerate() {
+            senders[i].send(phase).unwrap();
+        }
+        senders[0].send(0).unwrap();
+
+        let mut handles = vec![];
+        for (i, input) in receivers.into_iter().enumerate() {
+            let output = if i == 4 {
+                last_tx.clone()
+            } else {
+                senders[i + 1].clone()
+            };
+            handles.push(program.clone().spawn(input, output));
+        }
+        drop(last_tx);
+
+        // Pass the last amplifier's output back to the first, remembering the final value
+        let first = senders[0].clone();
+        drop(senders);
+        let mut last = None;
+        for value in last_rx {
+            last = Some(value);
+            let _ = first.send(value);
+        }
+        for handle in handles {
+            assert!(handle.join().unwrap().is_ok());
+        }
+        assert_eq!(last, Some(139629729));
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        let mut intcode_i = IntcodeI::new("3,100,1001,100,1,100,4,100,99").unwrap();
+        intcode_i.push_input(1);
+        intcode_i.step().unwrap();
+        let snapshot = intcode_i.snapshot();
+
+        assert_eq!(intcode_i.interpret().unwrap().output(), [2]);
+        assert_eq!(intcode_i.memory().len(), 101);
+        intcode_i.restore(&snapshot);
+        assert_eq!(intcode_i.memory()[100], 1);
+        intcode_i.write(100, 41);
+        assert_eq!(intcode_i.interpret().unwrap().output(), [42]);
+    }
+
+    #[test]
+    fn errors() {
         assert_eq!(
-            IntcodeI::new("1,1,1,4,99,5,6,0,99")
-                .interpret()
-                .unwrap()
-                .memory(),
-            [30, 1, 1, 4, 2, 5, 6, 0, 99]
+            IntcodeI::new("1,2,x").unwrap_err(),
+            IntcodeError::Parse("x".to_string())
         );
+
+        let cases = [
+            (
+                "1,0,0,0,42",
+                IntcodeError::UnknownOpcode { pc: 4, opcode: 42 },
+            ),
+            ("301,0,0,0,99", IntcodeError::UnknownMode { pc: 0, mode: 3 }),
+            ("11101,0,0,0,99", IntcodeError::ImmediateWrite { pc: 0 }),
+            (
+                "4,-3,99",
+                IntcodeError::NegativeAddress { pc: 0, address: -3 },
+            ),
+            ("3,0,99", IntcodeError::NoInput { pc: 0 }),
+            (
+                "1101,9223372036854775807,1,0,99",
+                IntcodeError::Overflow { pc: 0 },
+            ),
+            (
+                "1102,-9223372036854775808,-1,0,99",
+                IntcodeError::Overflow { pc: 0 },
+            ),
+            (
+                "109,9223372036854775807,109,1,99",
+                IntcodeError::Overflow { pc: 2 },
+            ),
+            (
+                "109,9223372036854775807,204,1,99",
+                IntcodeError::Overflow { pc: 2 },
+            ),
+        ];
+        for (program, error) in cases {
+            let mut intcode_i = IntcodeI::new(program).unwrap();
+            assert_eq!(intcode_i.interpret().unwrap_err(), error);
+        }
     }
 }
@@ -0,0 +1,380 @@
+use std::fmt::{self, Write as _};
+
+use rustc_hash::FxHashSet as HashSet;
+
+use super::{Event, IntcodeError, IntcodeI};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Mul,
+    In,
+    Out,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+impl Opcode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        Some(match code {
+            1 => Opcode::Add,
+            2 => Opcode::Mul,
+            3 => Opcode::In,
+            4 => Opcode::Out,
+            5 => Opcode::JumpIfTrue,
+            6 => Opcode::JumpIfFalse,
+            7 => Opcode::LessThan,
+            8 => Opcode::Equals,
+            9 => Opcode::AdjustBase,
+            99 => Opcode::Halt,
+            _ => return None,
+        })
+    }
+
+    pub fn params(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::In | Opcode::Out | Opcode::AdjustBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// The parameter the instruction writes to.
+    pub fn output_param(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => Some(3),
+            Opcode::In => Some(1),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Mul => "mul",
+            Opcode::In => "in",
+            Opcode::Out => "out",
+            Opcode::JumpIfTrue => "jnz",
+            Opcode::JumpIfFalse => "jz",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+}
+
+/// A parameter with its mode, displayed as `[12]` in position mode, `12` in immediate mode
+/// and `[rb+12]` in relative mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Param {
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Param::Position(address) => write!(f, "[{address}]"),
+            Param::Immediate(value) => write!(f, "{value}"),
+            Param::Relative(offset) if offset < 0 => write!(f, "[rb{offset}]"),
+            Param::Relative(offset) => write!(f, "[rb+{offset}]"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: Opcode,
+    pub params: Vec<Param>,
+}
+
+impl Instruction {
+    /// The number of values the instruction occupies.
+    pub fn size(&self) -> usize {
+        self.params.len() + 1
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.opcode.mnemonic())?;
+        for (i, param) in self.params.iter().enumerate() {
+            write!(f, "{}{param}", if i == 0 { " " } else { ", " })?;
+        }
+        Ok(())
+    }
+}
+
+/// What a tracer stops on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Before executing the instruction at this address.
+    Pc(usize),
+    /// After an instruction writes to this address.
+    Write(usize),
+}
+
+/// Why tracing stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceStop {
+    Halted,
+    NeedsInput,
+    Budget,
+    Breakpoint(Breakpoint),
+}
+
+/// Executes instructions one at a time, recording each and stopping on breakpoints or after
+/// a number of instructions.
+#[derive(Debug, Clone, Default)]
+pub struct Tracer {
+    budget: Option<usize>,
+    breakpoints: HashSet<Breakpoint>,
+    executed: usize,
+    log: Vec<Instruction>,
+    /// The pc breakpoint tracing last stopped at, which is passed over when tracing resumes.
+    stopped_at: Option<usize>,
+    /// Where the traced machine halted, so tracing it again doesn't repeat the halt.
+    halted_at: Option<usize>,
+}
+
+impl Tracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops after executing `budget` instructions in total.
+    pub fn budget(self, budget: usize) -> Self {
+        Tracer {
+            budget: Some(budget),
+            ..self
+        }
+    }
+
+    pub fn breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.insert(breakpoint);
+        self
+    }
+
+    pub fn executed(&self) -> usize {
+        self.executed
+    }
+
+    /// Every instruction executed, in order.
+    pub fn log(&self) -> &[Instruction] {
+        &self.log
+    }
+}
+
+impl IntcodeI {
+    /// The instruction at `address`, or `None` if it isn't a valid instruction.
+    pub fn decode(&self, address: usize) -> Option<Instruction> {
+        let code = self.read(address);
+        let opcode = Opcode::from_code(code % 100)?;
+        let params = (1..=opcode.params())
+            .map(|n| {
+                let value = self.read(address + n);
+                match code / 10i64.pow(n as u32 + 1) % 10 {
+                    0 => Some(Param::Position(value)),
+                    1 => Some(Param::Immediate(value)),
+                    2 => Some(Param::Relative(value)),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Instruction {
+            address,
+            opcode,
+            params,
+        })
+    }
+
+    /// A listing of memory as instructions with their raw values, values which aren't valid
+    /// instructions are listed as `data`.
+    pub fn disassemble(&self) -> String {
+        let mut s = String::new();
+        let mut address = 0;
+        while address < self.memory.len() {
+            let (len, text) = match self.decode(address) {
+                Some(instruction) => (instruction.size(), instruction.to_string()),
+                None => (1, format!("data {}", self.memory[address])),
+            };
+            let raw = (address..address + len).map(|a| self.read(a).to_string());
+            let raw = raw.collect::<Vec<_>>().join(",");
+            writeln!(s, "{address:>5}: {raw:<24} {text}").unwrap();
+            address += len;
+        }
+        s
+    }
+
+    /// Runs with `tracer` until the program halts, needs input or stops at a breakpoint or
+    /// the budget. Output is collected as with [`IntcodeI::step`].
+    ///
+    /// Tracing again continues past the breakpoint it stopped at.
+    pub fn trace(&mut self, tracer: &mut Tracer) -> Result<TraceStop, IntcodeError> {
+        let mut resumed_at = tracer.stopped_at.take();
+        loop {
+            if tracer.halted_at == Some(self.pc) && self.read(self.pc) % 100 == 99 {
+                return Ok(TraceStop::Halted);
+            }
+            if tracer
+                .budget
+                .is_some_and(|budget| tracer.executed >= budget)
+            {
+                return Ok(TraceStop::Budget);
+            }
+            let pc = Breakpoint::Pc(self.pc);
+            if resumed_at.take() != Some(self.pc) && tracer.breakpoints.contains(&pc) {
+                tracer.stopped_at = Some(self.pc);
+                return Ok(TraceStop::Breakpoint(pc));
+            }
+
+            let instruction = self.decode(self.pc);
+            let written = match &instruction {
+                Some(instruction) => match instruction.opcode.output_param() {
+                    Some(n) => self.param_address(n)?,
+                    None => None,
+                },
+                None => None,
+            };
+
+            let event = self.execute()?;
+            if let Event::NeedsInput = event {
+                return Ok(TraceStop::NeedsInput);
+            }
+            tracer.executed += 1;
+            tracer.log.extend(instruction);
+            match event {
+                Event::Output(value) => self.output.push(value),
+                Event::Halted => {
+                    tracer.halted_at = Some(self.pc);
+                    return Ok(TraceStop::Halted);
+                }
+                Event::Continue | Event::NeedsInput => {}
+            }
+
+            if let Some(address) = written {
+                let write = Breakpoint::Write(address);
+                if tracer.breakpoints.contains(&write) {
+                    return Ok(TraceStop::Breakpoint(write));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble() {
+        let intcode_i = IntcodeI::new("1002,4,3,4,33,109,-2,21101,1,2,5,3,7,99,42").unwrap();
+        assert_eq!(
+            intcode_i.disassemble(),
+            [
+                "    0: 1002,4,3,4               mul [4], 3, [4]",
+                "    4: 33                       data 33",
+                "    5: 109,-2                   arb -2",
+                "    7: 21101,1,2,5              add 1, 2, [rb+5]",
+                "   11: 3,7                      in [7]",
+                "   13: 99                       hlt",
+                "   14: 42                       data 42",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn trace_with_breakpoints() {
+        // Counts down from 3, outputting each value
+        let program = "4,10,1001,10,-1,10,1005,10,0,99,3";
+        let mut intcode_i = IntcodeI::new(program).unwrap();
+        let mut tracer = Tracer::new().breakpoint(Breakpoint::Pc(6));
+        assert_eq!(
+            intcode_i.trace(&mut tracer),
+            Ok(TraceStop::Breakpoint(Breakpoint::Pc(6)))
+        );
+        assert_eq!(intcode_i.read(10), 2);
+        assert_eq!(
+            tracer
+                .log()
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            ["out [10]", "add [10], -1, [10]"]
+        );
+        assert_eq!(
+            intcode_i.trace(&mut tracer),
+            Ok(TraceStop::Breakpoint(Breakpoint::Pc(6)))
+        );
+        assert_eq!(intcode_i.output(), [3, 2]);
+
+        let mut intcode_i = IntcodeI::new(program).unwrap();
+        let mut tracer = Tracer::new().budget(4);
+        assert_eq!(intcode_i.trace(&mut tracer), Ok(TraceStop::Budget));
+        assert_eq!(tracer.executed(), 4);
+        assert_eq!(intcode_i.output(), [3, 2]);
+
+        let mut intcode_i = IntcodeI::new(program).unwrap();
+        let mut tracer = Tracer::new().breakpoint(Breakpoint::Write(10));
+        for value in [2, 1, 0] {
+            assert_eq!(
+                intcode_i.trace(&mut tracer),
+                Ok(TraceStop::Breakpoint(Breakpoint::Write(10)))
+            );
+            assert_eq!(intcode_i.read(10), value);
+        }
+        assert_eq!(intcode_i.trace(&mut tracer), Ok(TraceStop::Halted));
+        assert_eq!(intcode_i.output(), [3, 2, 1]);
+
+        let mut intcode_i = IntcodeI::new("3,0,99").unwrap();
+        assert_eq!(
+            intcode_i.trace(&mut Tracer::new()),
+            Ok(TraceStop::NeedsInput)
+        );
+    }
+
+    #[test]
+    fn trace_breakpoint_at_start() {
+        let program = "4,10,1001,10,-1,10,1005,10,0,99,3";
+        let mut intcode_i = IntcodeI::new(program).unwrap();
+        let mut tracer = Tracer::new().breakpoint(Breakpoint::Pc(0));
+        for output in [[].as_slice(), &[3], &[3, 2]] {
+            assert_eq!(
+                intcode_i.trace(&mut tracer),
+                Ok(TraceStop::Breakpoint(Breakpoint::Pc(0)))
+            );
+            assert_eq!(intcode_i.output(), output);
+        }
+
+        // The halting instruction is executed too
+        assert_eq!(intcode_i.trace(&mut tracer), Ok(TraceStop::Halted));
+        assert_eq!(tracer.executed(), 10);
+        assert_eq!(tracer.log().last().unwrap().opcode, Opcode::Halt);
+    }
+
+    #[test]
+    fn trace_after_halting() {
+        let mut intcode_i = IntcodeI::new("104,7,99").unwrap();
+        let mut tracer = Tracer::new();
+        assert_eq!(intcode_i.trace(&mut tracer), Ok(TraceStop::Halted));
+        assert_eq!(tracer.executed(), 2);
+
+        // The halt isn't executed again
+        for _ in 0..2 {
+            assert_eq!(intcode_i.trace(&mut tracer), Ok(TraceStop::Halted));
+            assert_eq!(tracer.executed(), 2);
+            assert_eq!(tracer.log().len(), 2);
+        }
+        assert_eq!(intcode_i.output(), [7]);
+    }
+}
@@ -1,14 +1,14 @@
 use std::fmt::Display;
 
+crate::solution!(2020, 1, "Report Repair", parse_input -> Vec<u64>);
+
 fn parse_input(input: &str) -> Vec<u64> {
     input.lines().map(|l| l.parse().unwrap()).collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let entries = parse_input(input);
-
-    for i in &entries {
-        for j in &entries {
+pub fn part1(entries: &[u64]) -> impl Display {
+    for i in entries {
+        for j in entries {
             if *i + *j == 2020 {
                 return *i * *j;
             }
@@ -18,12 +18,10 @@ pub fn part1(input: &str) -> impl Display {
     panic!("no answer found")
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let entries = parse_input(input);
-
-    for i in &entries {
-        for j in &entries {
-            for k in &entries {
+pub fn part2(entries: &[u64]) -> impl Display {
+    for i in entries {
+        for j in entries {
+            for k in entries {
                 if *i + *j + *k == 2020 {
                     return *i * *j * *k;
                 }
@@ -54,23 +52,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "514579");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "514579");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "542619");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "241861950");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "241861950");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "32858450");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
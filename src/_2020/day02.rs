@@ -1,7 +1,9 @@
 use std::fmt::Display;
 
+crate::solution!(2020, 2, "Password Philosophy", parse_input -> Vec<Entry>);
+
 #[derive(Debug)]
-struct Entry {
+pub struct Entry {
     min: usize,
     max: usize,
     c: char,
@@ -38,13 +40,11 @@ fn parse_input(input: &str) -> Vec<Entry> {
     input.lines().map(Entry::parse).collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let entries = parse_input(input);
+pub fn part1(entries: &[Entry]) -> impl Display {
     entries.iter().filter(|e| e.is_valid_1()).count()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let entries = parse_input(input);
+pub fn part2(entries: &[Entry]) -> impl Display {
     entries.iter().filter(|e| e.is_valid_2()).count()
 }
 
@@ -65,23 +65,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "548");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "1");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "1");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "502");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+crate::solution!(2020, 3, "Toboggan Trajectory", parse_input -> Vec<Vec<bool>>);
+
 fn parse_input(input: &str) -> Vec<Vec<bool>> {
     input
         .lines()
@@ -7,8 +9,7 @@ fn parse_input(input: &str) -> Vec<Vec<bool>> {
         .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let rows = parse_input(input);
+pub fn part1(rows: &[Vec<bool>]) -> impl Display {
     rows.iter()
         .enumerate()
         .filter(|(i, row)| {
@@ -18,10 +19,9 @@ pub fn part1(input: &str) -> impl Display {
         .count()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(rows: &[Vec<bool>]) -> impl Display {
     let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
 
-    let rows = parse_input(input);
     let mut product: u64 = 1;
     for (dx, dy) in slopes {
         let mut cnt = 0;
@@ -59,23 +59,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "7");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "7");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "228");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "336");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "336");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "6818112000");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,7 +1,9 @@
 use std::fmt::Display;
 
+crate::solution!(2020, 4, "Passport Processing", parse_input -> Vec<Passport>);
+
 #[derive(Debug, Default)]
-struct Passport {
+pub struct Passport {
     byr: Option<String>,
     iyr: Option<String>,
     eyr: Option<String>,
@@ -114,13 +116,11 @@ fn parse_input(input: &str) -> Vec<Passport> {
     input.split("\n\n").map(Passport::parse).collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let passports = parse_input(input);
+pub fn part1(passports: &[Passport]) -> impl Display {
     passports.iter().filter(|p| p.is_valid_1()).count()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let passports = parse_input(input);
+pub fn part2(passports: &[Passport]) -> impl Display {
     passports.iter().filter(|p| p.is_valid_2()).count()
 }
 
@@ -153,13 +153,13 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "233");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     const INVALID_PASSPORTS: &str = indoc! {"
@@ -195,13 +195,13 @@ mod tests {
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(INVALID_PASSPORTS).to_string(), "0");
-        assert_eq!(part2(VALID_PASSPORTS).to_string(), "4");
+        assert_eq!(part2(&parse_input(INVALID_PASSPORTS)).to_string(), "0");
+        assert_eq!(part2(&parse_input(VALID_PASSPORTS)).to_string(), "4");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "111");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,10 +1,16 @@
-pub fn part1(input: &str) -> impl ToString {
-    let values = input.lines().map(|x| x.parse::<i32>().unwrap());
+use std::fmt::Display;
 
+crate::solution!(2021, 1, "Sonar Sweep", parse_input -> Vec<i32>);
+
+fn parse_input(input: &str) -> Vec<i32> {
+    input.lines().map(|x| x.parse().unwrap()).collect()
+}
+
+pub fn part1(values: &[i32]) -> impl Display {
     let mut increments = 0;
     let mut previous_value = None;
 
-    for value in values {
+    for &value in values {
         if let Some(v) = previous_value {
             if value > v {
                 increments += 1;
@@ -17,11 +23,7 @@ pub fn part1(input: &str) -> impl ToString {
     increments
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let values = input
-        .lines()
-        .map(|x| x.parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part2(values: &[i32]) -> impl Display {
     let windows = values.windows(3).map(|window| window.iter().sum::<i32>());
 
     let mut increments = 0;
@@ -64,23 +66,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "7");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "7");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1466");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "5");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "5");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1491");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
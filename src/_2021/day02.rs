@@ -1,13 +1,26 @@
-pub fn part1(input: &str) -> impl ToString {
-    let mut depth = 0;
-    let mut hor_distance = 0;
+use std::fmt::Display;
+
+crate::solution!(2021, 2, "Dive!", parse_input -> Vec<(&'a str, i32)>);
+
+fn parse_input(input: &str) -> Vec<(&str, i32)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut data = line.split(' ');
+
+            let direction = data.next().unwrap();
+            let x = data.next().unwrap().parse::<i32>().unwrap();
 
-    for line in input.lines() {
-        let mut data = line.split(' ');
+            (direction, x)
+        })
+        .collect()
+}
 
-        let direction = data.next().unwrap();
-        let x = data.next().unwrap().parse::<i32>().unwrap();
+pub fn part1(commands: &[(&str, i32)]) -> impl Display {
+    let mut depth = 0;
+    let mut hor_distance = 0;
 
+    for &(direction, x) in commands {
         match direction {
             "forward" => hor_distance += x,
             "up" => depth -= x,
@@ -19,17 +32,12 @@ pub fn part1(input: &str) -> impl ToString {
     hor_distance * depth
 }
 
-pub fn part2(input: &str) -> impl ToString {
+pub fn part2(commands: &[(&str, i32)]) -> impl Display {
     let mut aim = 0;
     let mut hor_distance = 0;
     let mut depth = 0;
 
-    for line in input.lines() {
-        let mut data = line.split(' ');
-
-        let direction = data.next().unwrap();
-        let x = data.next().unwrap().parse::<i32>().unwrap();
-
+    for &(direction, x) in commands {
         match direction {
             "forward" => {
                 hor_distance += x;
@@ -64,23 +72,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "150");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "150");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1815044");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "900");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "900");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1739283308");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
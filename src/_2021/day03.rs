@@ -1,10 +1,18 @@
-pub fn part1(input: &str) -> impl ToString {
-    let bit_count = input.lines().next().unwrap().len();
+use std::fmt::Display;
+
+crate::solution!(2021, 3, "Binary Diagnostic", parse_input -> Vec<&'a str>);
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(lines: &[&str]) -> impl Display {
+    let bit_count = lines[0].len();
 
     let mut zero_count = vec![0; bit_count];
     let mut one_count = vec![0; bit_count];
 
-    for line in input.lines() {
+    for line in lines {
         for (i, bit) in line.chars().enumerate() {
             let bit = bit.to_digit(10).unwrap();
             if bit == 0 {
@@ -29,11 +37,11 @@ pub fn part1(input: &str) -> impl ToString {
     gamma * epsilon
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let bit_count = input.lines().next().unwrap().len();
+pub fn part2(lines: &[&str]) -> impl Display {
+    let bit_count = lines[0].len();
 
     // Find oxygen generator rating
-    let mut oxygen_ratings = input.lines().map(|s| s.to_owned()).collect::<Vec<String>>();
+    let mut oxygen_ratings = lines.iter().map(|&s| s.to_owned()).collect::<Vec<String>>();
 
     for i in 0..bit_count {
         let mut zero_count = 0;
@@ -57,7 +65,7 @@ pub fn part2(input: &str) -> impl ToString {
     }
 
     // Find co2 scrubber rating
-    let mut co2_ratings = input.lines().map(|s| s.to_owned()).collect::<Vec<String>>();
+    let mut co2_ratings = lines.iter().map(|&s| s.to_owned()).collect::<Vec<String>>();
 
     for i in 0..bit_count {
         let mut zero_count = 0;
@@ -129,23 +137,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "198");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "198");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "749376");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "230");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "230");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "2372923");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
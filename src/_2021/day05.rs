@@ -1,27 +1,10 @@
 use core::cmp::Ordering;
-use std::{cmp, fmt, panic};
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl cmp::Ord for Point {
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        match self.x.cmp(&other.x) {
-            Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.y.cmp(&other.y)
-    }
-}
+use crate::util::point::Point;
+use crate::util::sparse_grid::SparseGrid;
 
-impl cmp::PartialOrd for Point {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
+crate::solution!(2021, 5, "Hydrothermal Venture", parse_input -> Vents);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum LineType {
@@ -35,12 +18,12 @@ enum LineType {
 
 #[derive(Debug, Clone, Copy)]
 struct Line {
-    start: Point,
-    end: Point,
+    start: Point<i64>,
+    end: Point<i64>,
 }
 
 impl Line {
-    fn new(start: Point, end: Point) -> Line {
+    fn new(start: Point<i64>, end: Point<i64>) -> Line {
         Line { start, end }
     }
 
@@ -58,120 +41,81 @@ impl Line {
     }
 }
 
-#[derive(Debug, Clone)]
-struct SeaBed {
-    width: usize,
-    height: usize,
-    grid: Vec<i32>,
-}
+/// The number of vents covering each point.
+type SeaBed = SparseGrid<u32>;
 
-impl fmt::Display for SeaBed {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let i = y * self.width + x;
-                let c = match self.grid[i] {
-                    0 => '.',
-                    n => char::from_digit(n as u32, 16).unwrap_or('+'),
-                };
-                write!(f, "{}", c)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
+fn plot_horizontal_vertical(sea_bed: &mut SeaBed, line: &Line) {
+    // Only consider horizontal or vertical lines
+    let line_type = line.line_type();
 
-impl SeaBed {
-    fn new(width: usize, height: usize) -> SeaBed {
-        SeaBed {
-            width,
-            height,
-            grid: vec![0; width * height],
-        }
-    }
+    // horizontal line
+    if line_type == LineType::Horizontal {
+        let y = line.start.y;
+        let x_start = line.start.x.min(line.end.x);
+        let x_end = line.start.x.max(line.end.x);
 
-    fn plot(&mut self, point: &Point) {
-        let index = point.y * self.width as i32 + point.x;
-        self.grid[index as usize] += 1;
+        for x in x_start..=x_end {
+            sea_bed[Point::new(x, y)] += 1;
+        }
     }
 
-    fn plot_horizontal_vertical(&mut self, line: &Line) {
-        // Only consider horizontal or vertical lines
-        let line_type = line.line_type();
-
-        // horizontal line
-        if line_type == LineType::Horizontal {
-            let y = line.start.y;
-            let x_start = line.start.x.min(line.end.x);
-            let x_end = line.start.x.max(line.end.x);
+    // vertical line
+    if line_type == LineType::Vertical {
+        let x = line.start.x;
+        let y_start = line.start.y.min(line.end.y);
+        let y_end = line.start.y.max(line.end.y);
 
-            for x in x_start..=x_end {
-                self.plot(&Point { x, y });
-            }
+        for y in y_start..=y_end {
+            sea_bed[Point::new(x, y)] += 1;
         }
+    }
+}
 
-        // vertical line
-        if line_type == LineType::Vertical {
-            let x = line.start.x;
-            let y_start = line.start.y.min(line.end.y);
-            let y_end = line.start.y.max(line.end.y);
+fn plot_line(sea_bed: &mut SeaBed, line: &Line) {
+    let line_type = line.line_type();
 
-            for y in y_start..=y_end {
-                self.plot(&Point { x, y });
-            }
-        }
-    }
+    match line_type {
+        LineType::Horizontal | LineType::Vertical => plot_horizontal_vertical(sea_bed, line),
+        LineType::ForwardDiagonal | LineType::BackwardDiagonal => {
+            let left_point = line.start.min(line.end);
+            let right_point = line.start.max(line.end);
+
+            let dx = right_point.x - left_point.x;
 
-    fn plot_line(&mut self, line: &Line) {
-        let line_type = line.line_type();
-
-        match line_type {
-            LineType::Horizontal | LineType::Vertical => self.plot_horizontal_vertical(line),
-            LineType::ForwardDiagonal | LineType::BackwardDiagonal => {
-                let left_point = line.start.min(line.end);
-                let right_point = line.start.max(line.end);
-
-                let dx = right_point.x - left_point.x;
-
-                for i in 0..=dx {
-                    let x = left_point.x + i;
-                    let y = if line_type == LineType::ForwardDiagonal {
-                        left_point.y + i
-                    } else {
-                        left_point.y - i
-                    };
-                    self.plot(&Point { x, y });
-                }
+            for i in 0..=dx {
+                let x = left_point.x + i;
+                let y = if line_type == LineType::ForwardDiagonal {
+                    left_point.y + i
+                } else {
+                    left_point.y - i
+                };
+                sea_bed[Point::new(x, y)] += 1;
             }
         }
     }
+}
 
-    fn count_danger(&self) -> usize {
-        self.grid.iter().filter(|&c| *c >= 2).count()
-    }
+fn count_danger(sea_bed: &SeaBed) -> usize {
+    sea_bed.iter().filter(|&(_, &c)| c >= 2).count()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let mut max_x = 0;
-    let mut max_y = 0;
+#[derive(Debug, Clone)]
+pub struct Vents {
+    lines: Vec<Line>,
+}
 
+fn parse_input(input: &str) -> Vents {
     let lines: Vec<_> = input
         .lines()
         .map(|line| {
             let mut points = line.split(" -> ").map(|coord_pair| {
                 let mut coord_pair_iterator =
-                    coord_pair.split(',').map(|s| s.parse::<i32>().unwrap());
-
-                let point = Point {
-                    x: coord_pair_iterator.next().unwrap(),
-                    y: coord_pair_iterator.next().unwrap(),
-                };
+                    coord_pair.split(',').map(|s| s.parse::<i64>().unwrap());
 
-                max_x = max_x.max(point.x);
-                max_y = max_y.max(point.y);
-
-                point
+                Point::new(
+                    coord_pair_iterator.next().unwrap(),
+                    coord_pair_iterator.next().unwrap(),
+                )
             });
             let point_a = points.next().unwrap();
             let point_b = points.next().unwrap();
@@ -184,60 +128,27 @@ pub fn part1(input: &str) -> impl ToString {
         })
         .collect();
 
-    let mut sea_bed = SeaBed::new(
-        (max_x + 1).try_into().unwrap(),
-        (max_y + 1).try_into().unwrap(),
-    );
-
-    for line in lines {
-        sea_bed.plot_horizontal_vertical(&line);
-    }
-
-    sea_bed.count_danger()
+    Vents { lines }
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let mut max_x = 0;
-    let mut max_y = 0;
+pub fn part1(vents: &Vents) -> impl fmt::Display {
+    let mut sea_bed = SeaBed::new(0);
 
-    let lines: Vec<_> = input
-        .lines()
-        .map(|line| {
-            let mut points = line.split(" -> ").map(|coord_pair| {
-                let mut coord_pair_iterator =
-                    coord_pair.split(',').map(|s| s.parse::<i32>().unwrap());
-
-                let point = Point {
-                    x: coord_pair_iterator.next().unwrap(),
-                    y: coord_pair_iterator.next().unwrap(),
-                };
-
-                max_x = max_x.max(point.x);
-                max_y = max_y.max(point.y);
-
-                point
-            });
-            let point_a = points.next().unwrap();
-            let point_b = points.next().unwrap();
+    for line in &vents.lines {
+        plot_horizontal_vertical(&mut sea_bed, line);
+    }
 
-            match point_a.cmp(&point_b) {
-                Ordering::Less => Line::new(point_a, point_b),
-                Ordering::Greater => Line::new(point_b, point_a),
-                Ordering::Equal => panic!("Invalid line"),
-            }
-        })
-        .collect();
+    count_danger(&sea_bed)
+}
 
-    let mut sea_bed = SeaBed::new(
-        (max_x + 1).try_into().unwrap(),
-        (max_y + 1).try_into().unwrap(),
-    );
+pub fn part2(vents: &Vents) -> impl fmt::Display {
+    let mut sea_bed = SeaBed::new(0);
 
-    for line in lines {
-        sea_bed.plot_line(&line);
+    for line in &vents.lines {
+        plot_line(&mut sea_bed, line);
     }
 
-    sea_bed.count_danger()
+    count_danger(&sea_bed)
 }
 
 #[cfg(test)]
@@ -264,23 +175,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "5");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "5");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "6548");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "12");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "12");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "19663");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,3 +1,7 @@
+use std::fmt::Display;
+
+crate::solution!(2021, 6, "Lanternfish", parse_input -> Vec<usize>);
+
 fn parse_input(input: &str) -> Vec<usize> {
     input
         .trim()
@@ -6,11 +10,10 @@ fn parse_input(input: &str) -> Vec<usize> {
         .collect()
 }
 
-fn comput_fish_count(input: &str, day: usize) -> usize {
-    let parsed_input = parse_input(input);
+fn comput_fish_count(fish: &[usize], day: usize) -> usize {
     let mut fish_cohorts = [0usize; 9];
 
-    for i in parsed_input {
+    for &i in fish {
         fish_cohorts[i] += 1;
     }
 
@@ -22,12 +25,12 @@ fn comput_fish_count(input: &str, day: usize) -> usize {
     fish_cohorts.iter().sum()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    comput_fish_count(input, 80)
+pub fn part1(fish: &[usize]) -> impl Display {
+    comput_fish_count(fish, 80)
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    comput_fish_count(input, 256)
+pub fn part2(fish: &[usize]) -> impl Display {
+    comput_fish_count(fish, 256)
 }
 
 #[cfg(test)]
@@ -45,23 +48,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "5934");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "5934");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "379414");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "26984457539");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "26984457539");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1705008653296");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,3 +1,7 @@
+use std::fmt::Display;
+
+crate::solution!(2021, 7, "The Treachery of Whales", parse_input -> Vec<i32>);
+
 fn parse_input(input: impl AsRef<str>) -> Vec<i32> {
     input
         .as_ref()
@@ -36,14 +40,12 @@ fn min_fuel_exp(vals: &[i32]) -> i32 {
         .unwrap()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-    min_fuel_linear(&parsed_input)
+pub fn part1(crabs: &[i32]) -> impl Display {
+    min_fuel_linear(crabs)
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-    min_fuel_exp(&parsed_input)
+pub fn part2(crabs: &[i32]) -> impl Display {
+    min_fuel_exp(crabs)
 }
 
 #[cfg(test)]
@@ -61,23 +63,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "37");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "37");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "352254");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "168");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "168");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "99053143");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,11 +1,14 @@
+use std::fmt::Display;
 use std::{collections::VecDeque, ops};
 
+crate::solution!(2021, 8, "Seven Segment Search", parse_input -> VecDeque<Entry>);
+
 fn char_to_index(c: char) -> usize {
     c as usize - 'a' as usize
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct SevenSegment(u8);
+pub struct SevenSegment(u8);
 
 impl SevenSegment {
     fn new(input: &str) -> SevenSegment {
@@ -39,9 +42,9 @@ impl ops::Not for SevenSegment {
     }
 }
 
-fn parse_input(
-    input: impl AsRef<str>,
-) -> VecDeque<(VecDeque<SevenSegment>, VecDeque<SevenSegment>)> {
+pub type Entry = (VecDeque<SevenSegment>, VecDeque<SevenSegment>);
+
+fn parse_input(input: impl AsRef<str>) -> VecDeque<Entry> {
     let mut lines = VecDeque::new();
     for line in input.as_ref().lines() {
         let mut split = line
@@ -57,10 +60,8 @@ fn parse_input(
     lines
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-
-    parsed_input
+pub fn part1(entries: &VecDeque<Entry>) -> impl Display {
+    entries
         .iter()
         .map(|(_, out)| {
             out.iter()
@@ -70,12 +71,11 @@ pub fn part1(input: &str) -> impl ToString {
         .sum::<usize>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-
+pub fn part2(entries: &VecDeque<Entry>) -> impl Display {
     let mut sum = 0;
 
-    for (mut in_segments, out_segments) in parsed_input {
+    for (in_segments, out_segments) in entries {
+        let mut in_segments = in_segments.clone();
         let mut seven_segments: [Option<SevenSegment>; 10] = [None; 10];
 
         while let Some(segment) = in_segments.pop_front() {
@@ -100,7 +100,7 @@ pub fn part2(input: &str) -> impl ToString {
         }
 
         let mut number = 0;
-        for segment in &out_segments {
+        for segment in out_segments {
             for (i, known_segment) in seven_segments.iter().enumerate() {
                 if let Some(inner_segment) = known_segment {
                     if segment == inner_segment {
@@ -140,23 +140,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "26");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "26");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "440");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "61229");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "61229");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1046281");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,4 +1,8 @@
-struct HeightMap {
+use std::fmt::Display;
+
+crate::solution!(2021, 9, "Smoke Basin", parse_input -> HeightMap);
+
+pub struct HeightMap {
     data: Vec<u32>,
     width: i32,
     height: i32,
@@ -89,18 +93,16 @@ fn parse_input(input: impl AsRef<str>) -> HeightMap {
     height_map
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-
-    let height = parsed_input.height;
-    let width = parsed_input.width;
+pub fn part1(height_map: &HeightMap) -> impl Display {
+    let height = height_map.height;
+    let width = height_map.width;
 
     let mut sum = 0;
 
     for y in 0..height {
         for x in 0..width {
-            if parsed_input.is_lowest_adjacent(x, y) {
-                sum += parsed_input.get(x, y).unwrap() + 1;
+            if height_map.is_lowest_adjacent(x, y) {
+                sum += height_map.get(x, y).unwrap() + 1;
             }
         }
     }
@@ -108,17 +110,15 @@ pub fn part1(input: &str) -> impl ToString {
     sum
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let parsed_input = parse_input(input);
-
-    let height = parsed_input.height;
-    let width = parsed_input.width;
+pub fn part2(height_map: &HeightMap) -> impl Display {
+    let height = height_map.height;
+    let width = height_map.width;
 
     let mut lowest_points = vec![];
 
     for y in 0..height {
         for x in 0..width {
-            if parsed_input.is_lowest_adjacent(x, y) {
+            if height_map.is_lowest_adjacent(x, y) {
                 lowest_points.push((x, y));
             }
         }
@@ -127,7 +127,7 @@ pub fn part2(input: &str) -> impl ToString {
     let mut three_largest_flood = [0; 3];
 
     for (x, y) in lowest_points {
-        let flood = parsed_input.flood(x, y).len();
+        let flood = height_map.flood(x, y).len();
         if flood > three_largest_flood[0] {
             three_largest_flood[2] = three_largest_flood[1];
             three_largest_flood[1] = three_largest_flood[0];
@@ -162,23 +162,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "15");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "15");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "591");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "1134");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "1134");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1113424");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
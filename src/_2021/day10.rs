@@ -1,4 +1,8 @@
-pub fn part1(input: &str) -> impl ToString {
+use std::fmt::Display;
+
+crate::solution!(2021, 10, "Syntax Scoring");
+
+pub fn part1(input: &str) -> impl Display {
     input
         .lines()
         .map(|line| {
@@ -28,7 +32,7 @@ pub fn part1(input: &str) -> impl ToString {
         .sum::<u64>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
+pub fn part2(input: &str) -> impl Display {
     let lines: Vec<_> = input.lines().collect();
 
     let mut line_score: Vec<u64> = vec![];
@@ -102,7 +106,7 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "240123");
+        util::check_answer(YEAR, DAY, 1, part1(&input));
     }
 
     #[test]
@@ -113,6 +117,6 @@ mod tests {
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "3260812321");
+        util::check_answer(YEAR, DAY, 2, part2(&input));
     }
 }
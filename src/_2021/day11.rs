@@ -1,4 +1,9 @@
-struct OctopusGrid {
+use std::fmt::Display;
+
+crate::solution!(2021, 11, "Dumbo Octopus", parse_input -> OctopusGrid);
+
+#[derive(Clone)]
+pub struct OctopusGrid {
     points: Vec<u32>,
     width: i32,
     height: i32,
@@ -75,16 +80,16 @@ fn parse_input(input: impl AsRef<str>) -> OctopusGrid {
     }
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let mut octopus_grid = parse_input(input);
+pub fn part1(octopus_grid: &OctopusGrid) -> impl Display {
+    let mut octopus_grid = octopus_grid.clone();
 
     let flash_count: usize = (0..100).map(|_| octopus_grid.step()).sum();
 
     flash_count
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let mut octopus_grid = parse_input(input);
+pub fn part2(octopus_grid: &OctopusGrid) -> impl Display {
+    let mut octopus_grid = octopus_grid.clone();
 
     let mut i = 1;
 
@@ -125,23 +130,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "1656");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "1656");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1773");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "195");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "195");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "494");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2021, 12, "Passage Pathing", parse_input -> CaveSystem);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Cave {
     Start,
@@ -22,7 +24,7 @@ impl Cave {
 }
 
 #[derive(Debug, Clone)]
-struct CaveSystem {
+pub struct CaveSystem {
     adjacency_list: HashMap<Cave, Vec<Cave>>,
 }
 
@@ -108,18 +110,14 @@ fn parse_input(input: impl AsRef<str>) -> CaveSystem {
     graph
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let cave_system = parse_input(input);
-
-    let paths: Vec<_> = find_paths(&Cave::Start, &cave_system, &HashMap::default(), false);
+pub fn part1(cave_system: &CaveSystem) -> impl Display {
+    let paths: Vec<_> = find_paths(&Cave::Start, cave_system, &HashMap::default(), false);
 
     paths.len()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let cave_system = parse_input(input);
-
-    let paths: Vec<_> = find_paths(&Cave::Start, &cave_system, &HashMap::default(), true);
+pub fn part2(cave_system: &CaveSystem) -> impl Display {
+    let paths: Vec<_> = find_paths(&Cave::Start, cave_system, &HashMap::default(), true);
 
     paths.len()
 }
@@ -156,23 +154,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "226");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "226");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "3761");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "3509");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "3509");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "99138");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
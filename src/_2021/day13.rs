@@ -1,3 +1,10 @@
+use std::fmt::Display;
+
+use crate::util::grid::Grid;
+use crate::util::ocr;
+
+crate::solution!(2021, 13, "Transparent Origami", parse_input -> Instructions);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum FoldAxis {
     X,
@@ -5,87 +12,52 @@ enum FoldAxis {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct FoldCommand {
+pub struct FoldCommand {
     coord: i32,
     axis: FoldAxis,
 }
 
-struct FoldablePaper {
-    points: Vec<bool>,
-    width: i32,
-    height: i32,
+#[derive(Clone)]
+pub struct FoldablePaper {
+    points: Grid<bool>,
 }
 
 impl FoldablePaper {
-    fn new(width: i32, height: i32) -> FoldablePaper {
+    fn new(width: usize, height: usize) -> FoldablePaper {
         FoldablePaper {
-            points: vec![false; (width * height).try_into().unwrap()],
-            width,
-            height,
+            points: Grid::new(width, height),
         }
     }
 
-    fn mark_point(&mut self, x: i32, y: i32) {
-        let index: usize = (self.width * y + x).try_into().unwrap();
-        self.points[index] = true;
+    fn mark_point(&mut self, x: usize, y: usize) {
+        self.points[(x, y)] = true;
     }
 
-    fn is_marked(&self, x: i32, y: i32) -> bool {
-        let index: usize = (self.width * y + x).try_into().unwrap();
-        *self.points.get(index).unwrap()
-    }
-
-    fn count_marked(&self) -> i32 {
-        self.points
-            .iter()
-            .filter(|v| **v)
-            .count()
-            .try_into()
-            .unwrap()
+    fn count_marked(&self) -> usize {
+        self.points.iter().filter(|(v, _)| **v).count()
     }
 
     fn fold(&mut self, fold: &FoldCommand) {
-        let (new_width, new_height) = match fold.axis {
-            FoldAxis::X => (self.width / 2, self.height),
-            FoldAxis::Y => (self.width, self.height / 2),
+        let (mirrored, new_width, new_height) = match fold.axis {
+            FoldAxis::X => (
+                self.points.flip_horizontal(),
+                self.points.width() / 2,
+                self.points.height(),
+            ),
+            FoldAxis::Y => (
+                self.points.flip_vertical(),
+                self.points.width(),
+                self.points.height() / 2,
+            ),
         };
 
-        let mut new_points = vec![false; (new_height * new_width) as usize];
-
-        match fold.axis {
-            FoldAxis::X => {
-                for y in 0..self.height {
-                    for x in 0..new_width {
-                        if self.is_marked(x, y) {
-                            new_points[(new_width * y + x) as usize] = true;
-                        }
-                        if self.is_marked(self.width - 1 - x, y) {
-                            new_points[(new_width * y + x) as usize] = true;
-                        }
-                    }
-                }
-            }
-            FoldAxis::Y => {
-                for y in 0..new_height {
-                    for x in 0..self.width {
-                        if self.is_marked(x, y) {
-                            new_points[(new_width * y + x) as usize] = true;
-                        }
-                        if self.is_marked(x, self.height - 1 - y) {
-                            new_points[(new_width * y + x) as usize] = true;
-                        }
-                    }
-                }
-            }
-        }
-
-        self.width = new_width;
-        self.height = new_height;
-        self.points = new_points;
+        self.points = Grid::from_fn(new_width, new_height, |p| self.points[p] || mirrored[p]);
     }
 }
 
-fn parse_input(input: impl AsRef<str>) -> (FoldablePaper, Vec<FoldCommand>) {
+pub type Instructions = (FoldablePaper, Vec<FoldCommand>);
+
+fn parse_input(input: impl AsRef<str>) -> Instructions {
     let mut split = input.as_ref().split("\n\n");
 
     let mut max_x = 0;
@@ -97,8 +69,8 @@ fn parse_input(input: impl AsRef<str>) -> (FoldablePaper, Vec<FoldCommand>) {
         .lines()
         .map(|s| {
             let mut line_split = s.split(',');
-            let x: i32 = line_split.next().unwrap().parse().unwrap();
-            let y: i32 = line_split.next().unwrap().parse().unwrap();
+            let x: usize = line_split.next().unwrap().parse().unwrap();
+            let y: usize = line_split.next().unwrap().parse().unwrap();
 
             max_x = max_x.max(x);
             max_y = max_y.max(y);
@@ -135,8 +107,8 @@ fn parse_input(input: impl AsRef<str>) -> (FoldablePaper, Vec<FoldCommand>) {
     (foldable_paper, commands)
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let (mut foldable_paper, fold_commands) = parse_input(input);
+pub fn part1((foldable_paper, fold_commands): &Instructions) -> impl Display {
+    let mut foldable_paper = foldable_paper.clone();
 
     let cmd = &fold_commands[0];
     foldable_paper.fold(cmd);
@@ -144,27 +116,19 @@ pub fn part1(input: &str) -> impl ToString {
     foldable_paper.count_marked()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let (mut foldable_paper, fold_commands) = parse_input(input);
+fn fold_all((foldable_paper, fold_commands): &Instructions) -> FoldablePaper {
+    let mut foldable_paper = foldable_paper.clone();
 
     for cmd in fold_commands {
-        foldable_paper.fold(&cmd);
+        foldable_paper.fold(cmd);
     }
 
-    let mut s = String::new();
-
-    for y in 0..foldable_paper.height {
-        for x in 0..foldable_paper.width {
-            if foldable_paper.is_marked(x, y) {
-                s.push('#');
-            } else {
-                s.push(' ');
-            }
-        }
-        s.push('\n');
-    }
+    foldable_paper
+}
 
-    s
+pub fn part2(instructions: &Instructions) -> impl Display {
+    // Failing shows the glyphs which weren't recognised
+    ocr::recognise(&fold_all(instructions).points).unwrap_or_else(|e| panic!("{e}"))
 }
 
 #[cfg(test)]
@@ -202,19 +166,20 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "17");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "17");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "664");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
+        let paper = fold_all(&parse_input(EXAMPLE));
         assert_eq!(
-            part2(EXAMPLE).to_string(),
+            util::render::text(&paper.points, |&marked| if marked { '#' } else { ' ' }),
             indoc::indoc! {"
                 #####
                 #   #
@@ -227,19 +192,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn part2_letters() {
+        // "HI" drawn upside down below the fold
+        let input = indoc::indoc! {"
+            0,12
+            3,12
+            0,11
+            3,11
+            0,10
+            1,10
+            2,10
+            3,10
+            0,9
+            3,9
+            0,8
+            3,8
+            0,7
+            3,7
+            5,12
+            6,12
+            7,12
+            6,11
+            6,10
+            6,9
+            6,8
+            5,7
+            6,7
+            7,7
+
+            fold along y=6
+        "};
+        assert_eq!(part2(&parse_input(input)).to_string(), "HI");
+    }
+
+    #[test]
+    #[should_panic(expected = "rows tall")]
+    fn part2_unreadable() {
+        part2(&parse_input(EXAMPLE));
+    }
+
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(
-            part2(&input).to_string(),
-            indoc::indoc! {"
-                #### ####   ## #  # #### #    ###  #    
-                #    #       # # #     # #    #  # #    
-                ###  ###     # ##     #  #    ###  #    
-                #    #       # # #   #   #    #  # #    
-                #    #    #  # # #  #    #    #  # #    
-                #### #     ##  #  # #### #### ###  #### 
-            "}
-        );
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
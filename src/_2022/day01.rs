@@ -1,29 +1,33 @@
 #![allow(clippy::needless_range_loop)]
 
-pub fn part1(input: &str) -> impl ToString {
-    let elfs = input.split("\n\n");
+use std::fmt::Display;
 
-    let mut most = 0;
-    for elf in elfs {
-        most = most.max(
+crate::solution!(2022, 1, "Calorie Counting", parse_input -> Vec<u32>);
+
+fn parse_input(input: &str) -> Vec<u32> {
+    input
+        .split("\n\n")
+        .map(|elf| {
             elf.lines()
                 .map(|line| line.parse::<u32>().unwrap())
-                .sum::<u32>(),
-        );
+                .sum::<u32>()
+        })
+        .collect()
+}
+
+pub fn part1(elfs: &[u32]) -> impl Display {
+    let mut most = 0;
+    for &elf in elfs {
+        most = most.max(elf);
     }
 
     most
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let elfs = input.split("\n\n");
-
+pub fn part2(elfs: &[u32]) -> impl Display {
     let mut top_3 = [0; 3];
-    for elf in elfs {
-        let mut sum = elf
-            .lines()
-            .map(|line| line.parse::<u32>().unwrap())
-            .sum::<u32>();
+    for &elf in elfs {
+        let mut sum = elf;
 
         for i in 0..3 {
             if sum > top_3[i] {
@@ -63,23 +67,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "24000");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "24000");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "69310");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "45000");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "45000");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "206104");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
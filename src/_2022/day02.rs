@@ -1,5 +1,9 @@
+use std::fmt::Display;
+
+crate::solution!(2022, 2, "Rock Paper Scissors", parse_input -> Vec<(HandShape, char)>);
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum HandShape {
+pub enum HandShape {
     Rock,
     Paper,
     Scissors,
@@ -56,13 +60,23 @@ impl HandShape {
     }
 }
 
-pub fn part1(input: &str) -> impl ToString {
+// Their shape and the unresolved second column, which each part reads differently
+fn parse_input(input: &str) -> Vec<(HandShape, char)> {
     input
         .lines()
         .map(|line| {
-            let (their_alpha, our_alpha) = line.split_once(' ').unwrap();
+            let (their_alpha, second) = line.split_once(' ').unwrap();
             let their = HandShape::from_alpha(their_alpha.chars().next().unwrap()).unwrap();
-            let our = HandShape::from_alpha(our_alpha.chars().next().unwrap()).unwrap();
+            (their, second.chars().next().unwrap())
+        })
+        .collect()
+}
+
+pub fn part1(rounds: &[(HandShape, char)]) -> impl Display {
+    rounds
+        .iter()
+        .map(|&(their, our_alpha)| {
+            let our = HandShape::from_alpha(our_alpha).unwrap();
             let score = our.outcome_score(&their) + our.score();
             println!("{:?} vs {:?} = {}", our, their, score);
             score
@@ -70,14 +84,11 @@ pub fn part1(input: &str) -> impl ToString {
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    input
-        .lines()
-        .map(|line| {
-            let (their_alpha, outcome) = line.split_once(' ').unwrap();
-            let their = HandShape::from_alpha(their_alpha.chars().next().unwrap()).unwrap();
-
-            let our = match outcome.chars().next().unwrap() {
+pub fn part2(rounds: &[(HandShape, char)]) -> impl Display {
+    rounds
+        .iter()
+        .map(|&(their, outcome)| {
+            let our = match outcome {
                 'X' => their.losing_shape(),
                 'Y' => their.draw_shape(),
                 'Z' => their.winning_shape(),
@@ -108,23 +119,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "15");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "15");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "12772");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "12");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "12");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "11618");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,9 @@
+use std::fmt::Display;
+
 use itertools::Itertools as _;
 
+crate::solution!(2022, 3, "Rucksack Reorganization", parse_input -> Vec<&'a str>);
+
 fn priority(c: char) -> u32 {
     if c.is_ascii_lowercase() {
         c as u32 - 'a' as u32 + 1
@@ -10,9 +14,13 @@ fn priority(c: char) -> u32 {
     }
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    input
-        .lines()
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(rucksacks: &[&str]) -> impl Display {
+    rucksacks
+        .iter()
         .map(|line| {
             let half = line.len() / 2;
             let (left, right) = line.split_at(half);
@@ -25,15 +33,13 @@ pub fn part1(input: &str) -> impl ToString {
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    input
-        .lines()
+pub fn part2(rucksacks: &[&str]) -> impl Display {
+    rucksacks
         .chunks(3)
-        .into_iter()
-        .map(|mut a| {
-            let line1 = a.next().unwrap();
-            let line2 = a.next().unwrap();
-            let line3 = a.next().unwrap();
+        .map(|a| {
+            let [line1, line2, line3] = a else {
+                panic!("incomplete group");
+            };
 
             for c in line1.chars() {
                 if line2.contains(c) && line3.contains(c) {
@@ -66,23 +72,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "157");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "157");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "7701");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn pasrt2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "70");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "70");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "2644");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,18 +1,24 @@
+use std::fmt::Display;
+
 use aho_corasick::AhoCorasick;
 
-pub fn part1(input: &str) -> impl ToString {
+crate::solution!(2023, 1, "Trebuchet?!");
+
+pub fn part1(input: &str) -> impl Display {
     input
         .lines()
         .map(|line| {
             let mut numbers = line.chars().filter(|c| c.is_ascii_digit());
             let first = numbers.next().unwrap().to_digit(10).unwrap();
-            let last = numbers.last().map_or(first, |c| c.to_digit(10).unwrap());
+            let last = numbers
+                .next_back()
+                .map_or(first, |c| c.to_digit(10).unwrap());
             first * 10 + last
         })
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
+pub fn part2(input: &str) -> impl Display {
     let nums = [
         "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4",
         "5", "6", "7", "8", "9",
@@ -49,7 +55,7 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "54601");
+        util::check_answer(YEAR, DAY, 1, part1(&input));
     }
 
     #[test]
@@ -61,6 +67,6 @@ mod tests {
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "54078");
+        util::check_answer(YEAR, DAY, 2, part2(&input));
     }
 }
@@ -1,12 +1,16 @@
+use std::fmt::Display;
+
+crate::solution!(2023, 2, "Cube Conundrum", parse_input -> Vec<Cubes>);
+
 #[derive(Debug, Clone)]
-struct Colors {
+pub struct Colors {
     red: u32,
     green: u32,
     blue: u32,
 }
 
 #[derive(Debug, Clone)]
-struct Cubes {
+pub struct Cubes {
     id: u32,
     segments: Vec<Colors>,
 }
@@ -84,25 +88,24 @@ impl Cubes {
     }
 }
 
-pub fn part1(input: &str) -> impl ToString {
+fn parse_input(input: &str) -> Vec<Cubes> {
+    input.lines().map(Cubes::parse_game).collect()
+}
+
+pub fn part1(games: &[Cubes]) -> impl Display {
     let reds = 12;
     let greens = 13;
     let blues = 14;
 
-    input
-        .lines()
-        .map(Cubes::parse_game)
+    games
+        .iter()
         .filter(|c| c.possible(reds, greens, blues))
         .map(|c| c.id)
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    input
-        .lines()
-        .map(Cubes::parse_game)
-        .map(|c| c.min_cubes_power())
-        .sum::<u32>()
+pub fn part2(games: &[Cubes]) -> impl Display {
+    games.iter().map(|c| c.min_cubes_power()).sum::<u32>()
 }
 
 #[cfg(test)]
@@ -124,23 +127,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "8");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "8");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2283");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "2286");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "2286");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "78669");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
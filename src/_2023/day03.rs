@@ -1,6 +1,12 @@
-pub fn part1(input: &str) -> impl ToString {
-    let engine: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+use std::fmt::Display;
 
+crate::solution!(2023, 3, "Gear Ratios", parse_input -> Vec<Vec<char>>);
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+pub fn part1(engine: &[Vec<char>]) -> impl Display {
     let height = engine.len();
     let width = engine[0].len();
 
@@ -59,9 +65,7 @@ pub fn part1(input: &str) -> impl ToString {
     sum
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let engine: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-
+pub fn part2(engine: &[Vec<char>]) -> impl Display {
     let height = engine.len();
     let width = engine[0].len();
 
@@ -161,23 +165,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "4361");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "4361");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "514969");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "467835");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "467835");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "78915902");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
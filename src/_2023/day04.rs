@@ -1,26 +1,36 @@
-fn parse_card(card: &str) -> impl Iterator<Item = (Vec<u32>, Vec<u32>)> + '_ {
-    card.lines().map(|line| {
-        let (_, numbers) = line.split_once(':').unwrap();
-        let (winning_str, our_str) = numbers.split_once('|').unwrap();
-        let winning = winning_str
-            .split_whitespace()
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<Vec<_>>();
-        let our = our_str
-            .split_whitespace()
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<Vec<_>>();
-
-        (winning, our)
-    })
+use std::fmt::Display;
+
+crate::solution!(2023, 4, "Scratchcards", parse_input -> Vec<Card>);
+
+pub type Card = (Vec<u32>, Vec<u32>);
+
+fn parse_input(input: &str) -> Vec<Card> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning_str, our_str) = numbers.split_once('|').unwrap();
+            let winning = winning_str
+                .split_whitespace()
+                .map(|n| n.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            let our = our_str
+                .split_whitespace()
+                .map(|n| n.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+
+            (winning, our)
+        })
+        .collect()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    parse_card(input)
+pub fn part1(cards: &[Card]) -> impl Display {
+    cards
+        .iter()
         .map(|(winning, our)| {
             let mut score = 0;
             for i in our {
-                if winning.contains(&i) {
+                if winning.contains(i) {
                     if score == 0 {
                         score += 1;
                     } else {
@@ -33,9 +43,7 @@ pub fn part1(input: &str) -> impl ToString {
         .sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let cards = parse_card(input).collect::<Vec<_>>();
-
+pub fn part2(cards: &[Card]) -> impl Display {
     let mut cards_played_cache = vec![0; cards.len()];
 
     for card_to_play in (0..cards.len()).rev() {
@@ -79,23 +87,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "13");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "13");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "21485");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "30");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "30");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "11024379");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
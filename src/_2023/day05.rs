@@ -1,16 +1,19 @@
+use std::fmt::Display;
 use std::str::Lines;
 
 use rayon::{iter::ParallelIterator, slice::ParallelSlice};
 
+crate::solution!(2023, 5, "If You Give A Seed A Fertilizer", parse_input -> (Vec<usize>, Maps));
+
 #[derive(Debug)]
-struct Mapping {
+pub struct Mapping {
     dest_range_start: usize,
     src_range_start: usize,
     range_len: usize,
 }
 
 #[derive(Debug)]
-struct Maps {
+pub struct Maps {
     seed_to_soil: Vec<Mapping>,
     soil_to_fertilizer: Vec<Mapping>,
     fertilizer_to_water: Vec<Mapping>,
@@ -54,14 +57,11 @@ fn parse_input(input: &str) -> (Vec<usize>, Maps) {
 }
 
 fn parse_map(lines: &mut Lines) -> Vec<Mapping> {
-    let mut lines = lines.skip(1);
+    let lines = lines.skip(1);
 
     let mut mappings = vec![];
 
-    loop {
-        let Some(line) = lines.next() else {
-            break;
-        };
+    for line in lines {
         if line.is_empty() {
             break;
         }
@@ -102,21 +102,19 @@ fn map_seed(maps: &Maps, seed: usize) -> usize {
     map_number(&maps.humidity_to_location, humidity)
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let (seeds, maps) = parse_input(input);
+pub fn part1((seeds, maps): &(Vec<usize>, Maps)) -> impl Display {
     seeds
         .iter()
-        .map(|seed| map_seed(&maps, *seed))
+        .map(|seed| map_seed(maps, *seed))
         .min()
         .unwrap()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let (seeds, maps) = parse_input(input);
+pub fn part2((seeds, maps): &(Vec<usize>, Maps)) -> impl Display {
     seeds
         .par_chunks(2)
         .flat_map(|chunk| chunk[0]..chunk[0] + chunk[1])
-        .map(|seed| map_seed(&maps, seed))
+        .map(|seed| map_seed(maps, seed))
         .min()
         .unwrap()
 }
@@ -168,24 +166,24 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "35");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "35");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "309796150");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "46");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "46");
     }
 
     #[tokio::test]
     #[ignore = "slow bruteforce"]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "50716416");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
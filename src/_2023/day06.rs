@@ -1,6 +1,10 @@
+use std::fmt::Display;
+
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
+crate::solution!(2023, 6, "Wait For It", parse_input -> (Vec<usize>, Vec<usize>));
+
 fn parse_input(input: &str) -> (Vec<usize>, Vec<usize>) {
     let mut lines = input.lines();
     let time = lines
@@ -27,18 +31,14 @@ fn ways_to_beat(time: usize, distance: usize) -> usize {
         .sum()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let (time, distance) = parse_input(input);
-
+pub fn part1((time, distance): &(Vec<usize>, Vec<usize>)) -> impl Display {
     time.iter()
         .zip(distance.iter())
         .map(|(time, distance)| ways_to_beat(*time, *distance))
         .product::<usize>()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let (time, distance) = parse_input(input);
-
+pub fn part2((time, distance): &(Vec<usize>, Vec<usize>)) -> impl Display {
     let time = time.iter().join("").parse::<usize>().unwrap();
     let distance = distance.iter().join("").parse::<usize>().unwrap();
 
@@ -61,23 +61,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "288");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "288");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "608902");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "71503");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "71503");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "46173809");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2023, 7, "Camel Cards", parse_input -> Vec<(&'a str, usize)>);
+
 #[derive(Debug)]
 struct Hand {
     cards: Vec<u8>,
@@ -123,16 +125,24 @@ fn char_to_u8(c: char, jokers: bool) -> u8 {
     }
 }
 
-fn parse_input(input: &str, jokers: bool) -> Vec<Hand> {
+fn parse_input(input: &str) -> Vec<(&str, usize)> {
     input
         .lines()
         .map(|line| {
             let (cards, bid) = line.split_once(' ').unwrap();
+            (cards, bid.parse::<usize>().unwrap())
+        })
+        .collect()
+}
+
+fn build_hands(hands: &[(&str, usize)], jokers: bool) -> Vec<Hand> {
+    hands
+        .iter()
+        .map(|&(cards, bid)| {
             let cards = cards
                 .chars()
                 .map(|c| char_to_u8(c, jokers))
                 .collect::<Vec<_>>();
-            let bid = bid.parse::<usize>().unwrap();
 
             let mut map = HashMap::default();
             let mut joker_count = 0;
@@ -160,14 +170,14 @@ fn parse_input(input: &str, jokers: bool) -> Vec<Hand> {
         .collect::<Vec<_>>()
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let mut hands = parse_input(input, false);
+pub fn part1(hands: &[(&str, usize)]) -> impl Display {
+    let mut hands = build_hands(hands, false);
     hands.sort_unstable_by(|a, b| a.compare_cards(b));
     sum_hands(&hands)
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let mut hands = parse_input(input, true);
+pub fn part2(hands: &[(&str, usize)]) -> impl Display {
+    let mut hands = build_hands(hands, true);
     hands.sort_unstable_by(|a, b| a.compare_cards(b));
     sum_hands(&hands)
 }
@@ -191,23 +201,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "6440");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "6440");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "250232501");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "5905");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "5905");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "249138943");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,6 +1,10 @@
 use crate::prelude::*;
 
-fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
+crate::solution!(2023, 8, "Haunted Wasteland", parse_input -> Network);
+
+pub type Network = (Vec<char>, HashMap<String, (String, String)>);
+
+fn parse_input(input: &str) -> Network {
     let mut lines = input.lines();
 
     let directions = lines.next().unwrap().chars().collect();
@@ -29,9 +33,7 @@ fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
     (directions, nodes)
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let (directions, nodes) = parse_input(input);
-
+pub fn part1((directions, nodes): &Network) -> impl Display {
     let mut node = "AAA".to_owned();
     let mut count = 0;
 
@@ -52,9 +54,7 @@ pub fn part1(input: &str) -> impl ToString {
     }
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let (directions, nodes) = parse_input(input);
-
+pub fn part2((directions, nodes): &Network) -> impl Display {
     let start_nodes = nodes
         .keys()
         .filter(|key| key.ends_with('A'))
@@ -84,7 +84,7 @@ pub fn part2(input: &str) -> impl ToString {
         counts.push(count);
     }
 
-    lcm(counts.into_iter())
+    lcm(counts)
 }
 
 #[cfg(test)]
@@ -118,14 +118,14 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(PART1_EXAMPLE_1).to_string(), "2");
-        assert_eq!(part1(PART1_EXAMPLE_2).to_string(), "6");
+        assert_eq!(part1(&parse_input(PART1_EXAMPLE_1)).to_string(), "2");
+        assert_eq!(part1(&parse_input(PART1_EXAMPLE_2)).to_string(), "6");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "19199");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     const PART2_EXAMPLE: &str = indoc::indoc! {"
@@ -143,12 +143,12 @@ mod tests {
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(PART2_EXAMPLE).to_string(), "6");
+        assert_eq!(part2(&parse_input(PART2_EXAMPLE)).to_string(), "6");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "13663968099527");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,57 +1,32 @@
-fn parse_input(input: &str) -> impl Iterator<Item = Vec<i64>> + '_ {
-    input.lines().map(|line| {
-        line.split_whitespace()
-            .map(|word| word.parse().unwrap())
-            .collect()
-    })
-}
+use std::fmt::Display;
 
-fn diffs_between(row: &[i64]) -> Vec<i64> {
-    let mut diffs = vec![];
-    for i in 0..row.len() - 1 {
-        diffs.push(row[i + 1] - row[i]);
-    }
-    diffs
-}
+use num::BigInt;
 
-pub fn part1(input: &str) -> impl ToString {
-    parse_input(input)
-        .map(|row| {
-            let mut diffs = vec![diffs_between(&row)];
+use crate::util::math::newton_forward;
 
-            loop {
-                let last = diffs.last().unwrap();
-                if last.iter().all(|&diff| diff == 0) {
-                    break;
-                }
-                diffs.push(diffs_between(last));
-            }
+crate::solution!(2023, 9, "Mirage Maintenance", parse_input -> Vec<Vec<i64>>);
 
-            diffs.iter().map(|diff| diff.last().unwrap()).sum::<i64>() + row.last().unwrap()
+fn parse_input(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|word| word.parse().unwrap())
+                .collect()
         })
-        .sum::<i64>()
+        .collect()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    parse_input(input)
-        .map(|row| {
-            let mut diffs = vec![diffs_between(&row)];
-
-            loop {
-                if diffs.last().unwrap().iter().all(|&diff| diff == 0) {
-                    break;
-                }
-
-                diffs.push(diffs_between(diffs.last().unwrap()));
-            }
+pub fn part1(rows: &[Vec<i64>]) -> impl Display {
+    rows.iter()
+        .map(|row| newton_forward(row, row.len() as i64))
+        .sum::<BigInt>()
+}
 
-            row.first().unwrap()
-                - diffs
-                    .iter()
-                    .rev()
-                    .fold(0, |acc, diff| diff.first().unwrap() - acc)
-        })
-        .sum::<i64>()
+pub fn part2(rows: &[Vec<i64>]) -> impl Display {
+    rows.iter()
+        .map(|row| newton_forward(row, -1))
+        .sum::<BigInt>()
 }
 
 #[cfg(test)]
@@ -71,23 +46,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "114");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "114");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2038472161");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "2");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1091");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,75 +1,57 @@
-use std::collections::VecDeque;
+use std::fmt::Display;
 
-use crate::util::grid::Grid;
-
-fn distance_bfs(map: &Grid<char>) -> Grid<Option<usize>> {
-    let mut distances: Grid<Option<usize>> = Grid::new(map.width(), map.height());
-    let mut queue = VecDeque::new();
+use rustc_hash::FxHashMap as HashMap;
 
-    // Find the starting point 'S' and mark it as distance 0
-    let start = map.find(|&c| c == 'S').unwrap();
-    distances[start] = Some(0);
-
-    // Add initial directions for 'S'
-    if start.y > 0 && matches!(map.get(start - (0, 1).into()), Some('|' | 'L' | 'J')) {
-        queue.push_back((start.x, start.y - 1, 1));
-    }
-    if start.y < map.height() - 1 && matches!(map.get(start + (0, 1).into()), Some('|' | '7' | 'F'))
-    {
-        queue.push_back((start.x, start.y + 1, 1));
-    }
-    if start.x > 0 && matches!(map.get(start - (1, 0).into()), Some('-' | 'L' | 'F')) {
-        queue.push_back((start.x - 1, start.y, 1));
-    }
-    if start.x < map.width() - 1 && matches!(map.get(start + (1, 0).into()), Some('-' | '7' | 'J'))
-    {
-        queue.push_back((start.x + 1, start.y, 1));
+use crate::util::grid::Grid;
+use crate::util::point::Direction::{self, East, North, South, West};
+use crate::util::point::Point;
+use crate::util::search;
+
+crate::solution!(2023, 10, "Pipe Maze", parse_input -> Grid<char>);
+
+/// The directions a pipe connects to.
+fn connections(pipe: char) -> &'static [Direction] {
+    match pipe {
+        '|' => &[North, South],
+        '-' => &[East, West],
+        'L' => &[North, East],
+        'J' => &[North, West],
+        '7' => &[South, West],
+        'F' => &[South, East],
+        _ => &[],
     }
+}
 
-    while let Some((x, y, distance)) = queue.pop_front() {
-        distances[(x, y)] = Some(distance);
-
-        // Get the directions we can go from this point
-        let (north, south, east, west) = match map[(x, y)] {
-            '|' => (true, true, false, false),
-            '-' => (false, false, true, true),
-            'L' => (true, false, true, false),
-            'J' => (true, false, false, true),
-            '7' => (false, true, false, true),
-            'F' => (false, true, true, false),
-            t => unreachable!("invalid map: {t}"),
-        };
-
-        // Add edges to the queue
-        if north && y > 0 && distances.get((x, y - 1).into()) == Some(&None) {
-            queue.push_back((x, y - 1, distance + 1));
-        }
-
-        if south && y < map.height() - 1 && distances.get((x, y + 1).into()) == Some(&None) {
-            queue.push_back((x, y + 1, distance + 1));
-        }
-
-        if east && x < map.width() - 1 && distances.get((x + 1, y).into()) == Some(&None) {
-            queue.push_back((x + 1, y, distance + 1));
-        }
+/// The distance along the loop to each of its pipes.
+fn distance_bfs(map: &Grid<char>) -> HashMap<Point<usize>, usize> {
+    let start = map.find(|&c| c == 'S').unwrap();
 
-        if west && x > 0 && distances.get((x - 1, y).into()) == Some(&None) {
-            queue.push_back((x - 1, y, distance + 1));
-        }
-    }
+    search::bfs([start], |&point| {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let next = point.checked_offset(direction.offset())?;
+            let &next_pipe = map.get(next)?;
+            let connected = match map[point] {
+                // 'S' connects to the pipes which connect back to it
+                'S' => connections(next_pipe).contains(&direction.reverse()),
+                pipe => connections(pipe).contains(&direction),
+            };
+            connected.then_some(next)
+        })
+    })
+    .distances
+}
 
-    distances
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let map = Grid::parse(input);
-    let distances = distance_bfs(&map);
-    distances.iter().filter_map(|d| *d.0).max().unwrap()
+pub fn part1(map: &Grid<char>) -> impl Display {
+    let distances = distance_bfs(map);
+    distances.into_values().max().unwrap()
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let map = Grid::parse(input);
-    let distances = distance_bfs(&map);
+pub fn part2(map: &Grid<char>) -> impl Display {
+    let distances = distance_bfs(map);
 
     // loop from left to right of each line applying the jordan curve theorem
     // https://en.wikipedia.org/wiki/Jordan_curve_theorem
@@ -77,7 +59,7 @@ pub fn part2(input: &str) -> impl ToString {
     for y in 0..map.height() {
         let mut i = 0;
         for x in 0..map.width() {
-            if distances[(x, y)].is_none() {
+            if !distances.contains_key(&Point::new(x, y)) {
                 if i % 2 == 1 {
                     count += 1;
                 }
@@ -109,13 +91,13 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE_1).to_string(), "8");
+        assert_eq!(part1(&parse_input(EXAMPLE_1)).to_string(), "8");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "6907");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     const EXAMPLE_2: &str = indoc::indoc! {"
@@ -132,12 +114,12 @@ mod tests {
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE_2).to_string(), "4");
+        assert_eq!(part2(&parse_input(EXAMPLE_2)).to_string(), "4");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "541");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
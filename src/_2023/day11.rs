@@ -1,35 +1,17 @@
 use crate::prelude::*;
 
-fn sum_distances(grid: &Grid<char>, space: usize) -> usize {
-    // Find the empty columns
-    let mut empty_y = vec![];
-    for y in 0..grid.height() {
-        let mut is_empty = true;
-        for x in 0..grid.width() {
-            if grid[(x, y)] == '#' {
-                is_empty = false;
-                break;
-            }
-        }
-        if is_empty {
-            empty_y.push(y);
-        }
-    }
+crate::solution!(2023, 11, "Cosmic Expansion", parse_input -> Grid<char>);
 
-    // Find the empty rows
-    let mut empty_x = vec![];
-    for x in 0..grid.width() {
-        let mut is_empty = true;
-        for y in 0..grid.height() {
-            if grid[(x, y)] == '#' {
-                is_empty = false;
-                break;
-            }
-        }
-        if is_empty {
-            empty_x.push(x);
-        }
-    }
+fn sum_distances(grid: &Grid<char>, space: usize) -> usize {
+    // Find the empty rows and columns
+    let empty_y = grid
+        .rows()
+        .positions(|row| !row.contains(&'#'))
+        .collect_vec();
+    let empty_x = grid
+        .columns()
+        .positions(|mut column| column.all(|&c| c != '#'))
+        .collect_vec();
 
     // Assign each point a number and store it in a hashmap
     let points: HashMap<_, _> = grid
@@ -71,14 +53,16 @@ fn sum_distances(grid: &Grid<char>, space: usize) -> usize {
     sum
 }
 
-pub fn part1(input: &str) -> impl ToString {
-    let grid = Grid::parse(input);
-    sum_distances(&grid, 2)
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<char>) -> impl Display {
+    sum_distances(grid, 2)
 }
 
-pub fn part2(input: &str) -> impl ToString {
-    let grid = Grid::parse(input);
-    sum_distances(&grid, 1000000)
+pub fn part2(grid: &Grid<char>) -> impl Display {
+    sum_distances(grid, 1000000)
 }
 
 #[cfg(test)]
@@ -105,23 +89,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "374");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "374");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "9734203");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "82000210");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "82000210");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "568914596391");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
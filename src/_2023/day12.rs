@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
+crate::solution!(2023, 12, "Hot Springs", parse_input -> Vec<Record>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Spring {
+pub enum Spring {
     Operational,
     Damaged,
     Unknown,
@@ -19,7 +21,7 @@ impl Spring {
 }
 
 #[derive(Debug, Clone)]
-struct Record {
+pub struct Record {
     springs: Vec<Spring>,
     counts: Vec<u64>,
 }
@@ -36,7 +38,7 @@ impl Record {
         let mut record = self.clone();
         for val in &mut record.springs {
             if *val == Spring::Unknown {
-                *val = if i % 2 == 0 {
+                *val = if i.is_multiple_of(2) {
                     Spring::Damaged
                 } else {
                     Spring::Operational
@@ -88,15 +90,14 @@ fn parse_input(input: &str) -> Vec<Record> {
     input.lines().map(Record::parse).collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let records = parse_input(input);
+pub fn part1(records: &[Record]) -> impl Display {
     records
         .par_iter()
         .map(|r| r.brute_force_arangements())
         .sum::<usize>()
 }
 
-pub fn part2(_input: &str) -> impl Display {
+pub fn part2(_records: &[Record]) -> impl Display {
     0
 }
 
@@ -129,25 +130,25 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "21");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "21");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "7718");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[ignore = "not finished"]
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "525152");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "525152");
     }
 
     #[ignore = "not finished"]
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,19 +1,19 @@
 use crate::prelude::*;
 
+crate::solution!(2023, 13, "Point of Incidence", parse_input -> Vec<Grid<char>>);
+
 fn parse_input(input: &str) -> Vec<Grid<char>> {
     input.split("\n\n").map(Grid::parse).collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let s = parse_input(input);
-    for grid in s {
+pub fn part1(grids: &[Grid<char>]) -> impl Display {
+    for grid in grids {
         grid.get(Point::new(grid.height(), grid.width()));
     }
     0
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let _ = parse_input(input);
+pub fn part2(_grids: &[Grid<char>]) -> impl Display {
     0
 }
 
@@ -46,23 +46,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "0");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "0");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -3,6 +3,8 @@ use rustc_hash::FxBuildHasher;
 
 use crate::prelude::*;
 
+crate::solution!(2024, 1, "Historian Hysteria");
+
 const CAPACITY: usize = 1024;
 
 pub fn part1(input: &str) -> impl Display {
@@ -131,7 +133,7 @@ mod tests {
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2113135");
+        util::check_answer(YEAR, DAY, 1, part1(&input));
     }
 
     #[test]
@@ -142,6 +144,6 @@ mod tests {
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "19097157");
+        util::check_answer(YEAR, DAY, 2, part2(&input));
     }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 2, "Red-Nosed Reports", parse_input -> Vec<Vec<i64>>);
+
 fn parse_input(input: &str) -> Vec<Vec<i64>> {
     input
         .lines()
@@ -11,15 +13,13 @@ fn parse_input(input: &str) -> Vec<Vec<i64>> {
         .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let grid = parse_input(input);
-
+pub fn part1(grid: &[Vec<i64>]) -> impl Display {
     let mut safe = 0;
     'line_loop: for line in grid {
         let mut is_increasing = None;
         let mut prev = None;
 
-        for curr in line {
+        for &curr in line {
             if let Some(prev) = prev {
                 let increase = curr - prev > 0;
                 if let Some(is_increasing) = is_increasing {
@@ -43,9 +43,7 @@ pub fn part1(input: &str) -> impl Display {
     safe
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let grid = parse_input(input);
-
+pub fn part2(grid: &[Vec<i64>]) -> impl Display {
     let mut safe = 0;
     for line in grid {
         'try_loop: for i in 0..line.len() {
@@ -101,23 +99,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "2");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "639");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "4");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "4");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "674");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -4,13 +4,16 @@ use regex::Regex;
 
 use crate::prelude::*;
 
-enum Instruction {
+crate::solution!(2024, 3, "Mull It Over", parse_input -> Vec<Instruction>);
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
     Mul(u64, u64),
     Do,
     Dont,
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = Instruction> + '_ {
+fn parse_input(input: &str) -> Vec<Instruction> {
     static RE: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"(mul\((\d*),(\d*)\)|do\(\)|don't\(\))").unwrap());
 
@@ -23,10 +26,12 @@ fn parse_input(input: &str) -> impl Iterator<Item = Instruction> + '_ {
                 m.get(3).unwrap().as_str().parse().unwrap(),
             ),
         })
+        .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    parse_input(input)
+pub fn part1(instructions: &[Instruction]) -> impl Display {
+    instructions
+        .iter()
         .map(|i| match i {
             Instruction::Mul(a, b) => a * b,
             _ => 0,
@@ -34,9 +39,10 @@ pub fn part1(input: &str) -> impl Display {
         .sum::<u64>()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    parse_input(input)
-        .fold((true, 0), |(enabled, i), instruction| match instruction {
+pub fn part2(instructions: &[Instruction]) -> impl Display {
+    instructions
+        .iter()
+        .fold((true, 0), |(enabled, i), &instruction| match instruction {
             Instruction::Mul(a, b) if enabled => (enabled, i + a * b),
             Instruction::Mul(_, _) => (enabled, i),
             Instruction::Do => (true, i),
@@ -59,13 +65,13 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE_1).to_string(), "161");
+        assert_eq!(part1(&parse_input(EXAMPLE_1)).to_string(), "161");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "188116424");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     const EXAMPLE_2: &str =
@@ -73,12 +79,12 @@ mod tests {
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE_2).to_string(), "48");
+        assert_eq!(part2(&parse_input(EXAMPLE_2)).to_string(), "48");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "104245808");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 4, "Ceres Search", parse_input -> Grid<char>);
+
 fn parse_input(input: &str) -> Grid<char> {
     Grid::parse(input)
 }
@@ -20,14 +22,13 @@ fn check_word(grid: &Grid<char>, word: &str, start: Point<i64>, offset: Point<i6
     true
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let grid = parse_input(input);
+pub fn part1(grid: &Grid<char>) -> impl Display {
     let mut count = 0;
     for i in 0..grid.width() {
         for j in 0..grid.height() {
             for word in ["XMAS", "SAMX"] {
                 for offset in [(1, 0), (0, 1), (1, 1), (-1, 1)] {
-                    if check_word(&grid, word, (i as i64, j as i64).into(), offset.into()) {
+                    if check_word(grid, word, (i as i64, j as i64).into(), offset.into()) {
                         count += 1;
                     };
                 }
@@ -37,8 +38,7 @@ pub fn part1(input: &str) -> impl Display {
     count
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let grid = parse_input(input);
+pub fn part2(grid: &Grid<char>) -> impl Display {
     let patterns = [
         [['M', '.', 'S'], ['.', 'A', '.'], ['M', '.', 'S']],
         [['S', '.', 'M'], ['.', 'A', '.'], ['S', '.', 'M']],
@@ -96,23 +96,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "18");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "18");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "2532");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "9");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "9");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1941");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -2,7 +2,9 @@ use std::cmp::Ordering;
 
 use crate::prelude::*;
 
-struct Manual {
+crate::solution!(2024, 5, "Print Queue", parse_input -> Manual);
+
+pub struct Manual {
     rules: Vec<(u32, u32)>,
     page_numbers_lines: Vec<Vec<u32>>,
 }
@@ -51,16 +53,14 @@ fn split_manuals(manual: &Manual) -> (Vec<Vec<u32>>, Vec<Vec<u32>>) {
     (valid, invalid)
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let manual = parse_input(input);
-    let (valid, _) = split_manuals(&manual);
+pub fn part1(manual: &Manual) -> impl Display {
+    let (valid, _) = split_manuals(manual);
 
     valid.iter().map(|v| v[v.len() / 2]).sum::<u32>()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let manual = parse_input(input);
-    let (_, mut invalid) = split_manuals(&manual);
+pub fn part2(manual: &Manual) -> impl Display {
+    let (_, mut invalid) = split_manuals(manual);
 
     invalid.iter_mut().for_each(|pages| {
         pages.sort_unstable_by(|a, b| {
@@ -120,23 +120,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "143");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "143");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "5964");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "123");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "123");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "4719");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
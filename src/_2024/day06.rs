@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 6, "Guard Gallivant", parse_input -> Grid<u8>);
+
 fn parse_input(input: &str) -> Grid<u8> {
     Grid::parse_bytes(input)
 }
@@ -8,18 +10,10 @@ fn solve(mut map: Grid<u8>) -> Option<Grid<bool>> {
     let mut visited = Grid::new(map.width(), map.height());
 
     // Find the ^, >, <, or v character
-    let mut char = b'0';
-    let mut pos: Point<i32> = Point::new(0, 0);
-
-    's: for i in 0..map.height() {
-        for j in 0..map.width() {
-            if matches!(map[i][j], b'^' | b'>' | b'<' | b'v') {
-                pos = Point::new(j as i32, i as i32);
-                char = map[i][j];
-                break 's;
-            }
-        }
-    }
+    let mut pos = map
+        .find(|c| matches!(c, b'^' | b'>' | b'<' | b'v'))
+        .unwrap();
+    let mut char = map[pos];
 
     let mut count = 0;
     loop {
@@ -28,32 +22,26 @@ fn solve(mut map: Grid<u8>) -> Option<Grid<bool>> {
             return None;
         }
 
-        visited[pos.y as usize][pos.x as usize] = true;
+        visited[pos] = true;
 
-        let (move_dir, next) = match char {
-            b'^' => (Point::new(0, -1), b'>'),
-            b'v' => (Point::new(0, 1), b'<'),
-            b'>' => (Point::new(1, 0), b'v'),
-            b'<' => (Point::new(-1, 0), b'^'),
+        let (direction, next) = match char {
+            b'^' => (Direction::North, b'>'),
+            b'v' => (Direction::South, b'<'),
+            b'>' => (Direction::East, b'v'),
+            b'<' => (Direction::West, b'^'),
             _ => unreachable!(),
         };
 
-        let next_pos = Point::new(pos.x + move_dir.x, pos.y + move_dir.y);
-
-        if next_pos.x < 0
-            || next_pos.x as usize >= map.width()
-            || next_pos.y < 0
-            || next_pos.y as usize >= map.height()
-        {
+        let Some(next_pos) = map.checked_offset(pos, direction.offset()) else {
             break;
-        }
+        };
 
-        if map[next_pos.y as usize][next_pos.x as usize] != b'#' {
-            map[pos.y as usize][pos.x as usize] = b'.';
-            map[next_pos.y as usize][next_pos.x as usize] = char;
+        if map[next_pos] != b'#' {
+            map[pos] = b'.';
+            map[next_pos] = char;
             pos = next_pos;
         } else {
-            map[pos.y as usize][pos.x as usize] = next;
+            map[pos] = next;
             char = next;
         }
     }
@@ -63,15 +51,12 @@ fn solve(mut map: Grid<u8>) -> Option<Grid<bool>> {
     Some(visited)
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let map = parse_input(input);
-    let visited = solve(map).unwrap();
+pub fn part1(map: &Grid<u8>) -> impl Display {
+    let visited = solve(map.clone()).unwrap();
     visited.iter().filter(|(&c, _)| c).count()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let map = parse_input(input);
-
+pub fn part2(map: &Grid<u8>) -> impl Display {
     let mut positions = Vec::with_capacity(map.width() * map.height());
     for i in 0..map.width() {
         for j in 0..map.height() {
@@ -115,23 +100,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "41");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "41");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "5086");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "6");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "6");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1770");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
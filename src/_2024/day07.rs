@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 7, "Bridge Repair", parse_input -> Vec<Equation>);
+
 #[derive(Debug, Clone)]
-struct Equation {
+pub struct Equation {
     left: u64,
     right: Vec<u64>,
 }
@@ -60,8 +62,7 @@ fn solve<const N: usize>(equation: &Equation, operations: [Operation; N]) -> Opt
     None
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let equations = parse_input(input);
+pub fn part1(equations: &[Equation]) -> impl Display {
     let operations = [Operation::Add, Operation::Multiply];
     equations
         .par_iter()
@@ -69,8 +70,7 @@ pub fn part1(input: &str) -> impl Display {
         .sum::<u64>()
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let equations = parse_input(input);
+pub fn part2(equations: &[Equation]) -> impl Display {
     let operations = [Operation::Add, Operation::Multiply, Operation::Concatenate];
     equations
         .par_iter()
@@ -101,23 +101,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "3749");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "3749");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1038838357795");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "11387");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "11387");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "254136560217241");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
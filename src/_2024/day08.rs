@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 8, "Resonant Collinearity", parse_input -> Grid<u8>);
+
 fn parse_input(input: &str) -> Grid<u8> {
     Grid::parse_bytes(input)
 }
@@ -79,19 +81,18 @@ fn get_annodes(
     annodes
 }
 
-fn solve(input: &str, part2: bool) -> usize {
-    let grid = parse_input(input);
-    let antennas = get_antennas(&grid);
+fn solve(grid: &Grid<u8>, part2: bool) -> usize {
+    let antennas = get_antennas(grid);
     let annodes = get_annodes(&antennas, grid.width(), grid.height(), part2);
     annodes.iter().map(|(&c, _)| c as usize).sum::<usize>()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    solve(input, false)
+pub fn part1(grid: &Grid<u8>) -> impl Display {
+    solve(grid, false)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    solve(input, true)
+pub fn part2(grid: &Grid<u8>) -> impl Display {
+    solve(grid, true)
 }
 
 #[cfg(test)]
@@ -120,23 +121,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "14");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "14");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "336");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "34");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "34");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1131");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
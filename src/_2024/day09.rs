@@ -1,9 +1,15 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 9, "Disk Fragmenter", parse_input -> Vec<u32>);
+
 const DISK_EMPTY: u16 = u16::MAX;
 
-fn parse_input(input: &str) -> impl Iterator<Item = u32> + '_ {
-    input.trim().chars().map(|i| i.to_digit(10).unwrap())
+fn parse_input(input: &str) -> Vec<u32> {
+    input
+        .trim()
+        .chars()
+        .map(|i| i.to_digit(10).unwrap())
+        .collect()
 }
 
 fn expand_map(map: impl Iterator<Item = u32>) -> Vec<u16> {
@@ -72,16 +78,14 @@ fn checksum(disk: &[u16]) -> usize {
         .sum::<usize>()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let map = parse_input(input);
-    let mut disk = expand_map(map);
+pub fn part1(map: &[u32]) -> impl Display {
+    let mut disk = expand_map(map.iter().copied());
     defrag_1(&mut disk);
     checksum(&disk)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let map = parse_input(input);
-    let mut disk = expand_map(map);
+pub fn part2(map: &[u32]) -> impl Display {
+    let mut disk = expand_map(map.iter().copied());
     defrag_2(&mut disk);
     checksum(&disk)
 }
@@ -99,23 +103,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "1928");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "1928");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "6432869891895");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "2858");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "2858");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "6467290479134");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
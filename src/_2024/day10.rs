@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+crate::solution!(2024, 10, "Hoof It", parse_input -> Grid<u8>);
+
 fn parse_input(input: &str) -> Grid<u8> {
     Grid::parse_bytes(input)
 }
@@ -9,14 +11,7 @@ enum Mode {
     Unique,
 }
 
-const DIRECTIONS: [Point<i64>; 4] = [
-    Point::new(-1, 0),
-    Point::new(1, 0),
-    Point::new(0, -1),
-    Point::new(0, 1),
-];
-
-fn search(grid: Grid<u8>, mode: Mode) -> usize {
+fn search(grid: &Grid<u8>, mode: Mode) -> usize {
     let zeros: Vec<_> = grid
         .iter()
         .filter(|(&i, _)| i == b'0')
@@ -25,24 +20,20 @@ fn search(grid: Grid<u8>, mode: Mode) -> usize {
     let mut trail_scores = vec![];
 
     for zero in zeros {
-        let zero: Point<i64> = zero.try_into().unwrap();
-        let mut queue = DIRECTIONS.map(|p| (zero + p, 0)).to_vec();
+        let mut queue = vec![(zero, 0)];
 
         let mut dest_any = HashSet::default();
         let mut dest_unique = Vec::new();
 
         while let Some((point, prev)) = queue.pop() {
-            if let Ok(point_usize) = point.try_into() {
-                let point_usize: Point<usize> = point_usize;
-                if let Some(&next) = grid.get(point_usize) {
-                    let next = next.saturating_sub(b'0');
-                    if next == prev + 1 {
-                        if next == 9 {
-                            dest_any.insert(point_usize);
-                            dest_unique.push(point_usize);
-                        } else {
-                            queue.extend(DIRECTIONS.map(|p| (point + p, next)));
-                        }
+            for (neighbor, &next) in grid.neighbors4(point) {
+                let next = next.saturating_sub(b'0');
+                if next == prev + 1 {
+                    if next == 9 {
+                        dest_any.insert(neighbor);
+                        dest_unique.push(neighbor);
+                    } else {
+                        queue.push((neighbor, next));
                     }
                 }
             }
@@ -58,13 +49,11 @@ fn search(grid: Grid<u8>, mode: Mode) -> usize {
     trail_scores.iter().sum::<usize>()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let grid = parse_input(input);
+pub fn part1(grid: &Grid<u8>) -> impl Display {
     search(grid, Mode::Any)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let grid = parse_input(input);
+pub fn part2(grid: &Grid<u8>) -> impl Display {
     search(grid, Mode::Unique)
 }
 
@@ -139,29 +128,29 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE_1).to_string(), "2");
-        assert_eq!(part1(EXAMPLE_2).to_string(), "4");
-        assert_eq!(part1(EXAMPLE_3).to_string(), "3");
-        assert_eq!(part1(EXAMPLE_4).to_string(), "36");
+        assert_eq!(part1(&parse_input(EXAMPLE_1)).to_string(), "2");
+        assert_eq!(part1(&parse_input(EXAMPLE_2)).to_string(), "4");
+        assert_eq!(part1(&parse_input(EXAMPLE_3)).to_string(), "3");
+        assert_eq!(part1(&parse_input(EXAMPLE_4)).to_string(), "36");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "841");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE_5).to_string(), "3");
-        assert_eq!(part2(EXAMPLE_2).to_string(), "13");
-        assert_eq!(part2(EXAMPLE_6).to_string(), "227");
-        assert_eq!(part2(EXAMPLE_4).to_string(), "81");
+        assert_eq!(part2(&parse_input(EXAMPLE_5)).to_string(), "3");
+        assert_eq!(part2(&parse_input(EXAMPLE_2)).to_string(), "13");
+        assert_eq!(part2(&parse_input(EXAMPLE_6)).to_string(), "227");
+        assert_eq!(part2(&parse_input(EXAMPLE_4)).to_string(), "81");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "1875");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
-type Map = HashMap<u64, usize>;
+crate::solution!(2024, 11, "Plutonian Pebbles", parse_input -> Map);
+
+pub type Map = HashMap<u64, usize>;
 
 fn parse_input(input: &str) -> Map {
     input
@@ -41,14 +43,12 @@ fn run(mut stones: Map, steps: usize) -> usize {
     stones.values().sum()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let stones = parse_input(input);
-    run(stones, 25)
+pub fn part1(stones: &Map) -> impl Display {
+    run(stones.clone(), 25)
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let stones = parse_input(input);
-    run(stones, 75)
+pub fn part2(stones: &Map) -> impl Display {
+    run(stones.clone(), 75)
 }
 
 #[cfg(test)]
@@ -64,18 +64,18 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "55312");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "55312");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "190865");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "225404711855335");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -1,11 +1,7 @@
 use crate::prelude::*;
+use crate::util::search;
 
-const DIRECTIONS: [Point<i64>; 4] = [
-    Point::new(-1, 0),
-    Point::new(1, 0),
-    Point::new(0, -1),
-    Point::new(0, 1),
-];
+crate::solution!(2024, 12, "Garden Groups", parse_input -> Grid<u8>);
 
 fn parse_input(input: &str) -> Grid<u8> {
     Grid::parse_bytes(input)
@@ -20,32 +16,12 @@ fn solve(grid: &Grid<u8>) -> Output {
     let mut regions = HashSet::default();
 
     // flood each region
-    for (search_plant, search_point) in grid.iter() {
-        let mut queue = vec![search_point];
-        let mut visited = HashSet::default();
-        let mut local_region = Vec::new();
-
-        while let Some(queue_point) = queue.pop() {
-            if let Some(plant) = grid.get(queue_point) {
-                if plant == search_plant {
-                    let p = queue_point + Point::new(1, 1);
-                    if let Err(i) = local_region.binary_search(&p) {
-                        local_region.insert(i, p);
-                    }
-
-                    queue.extend(DIRECTIONS.iter().filter_map(|direction| {
-                        let point: Point<usize> = Point::new(
-                            direction.x + queue_point.x as i64,
-                            direction.y + queue_point.y as i64,
-                        )
-                        .try_into()
-                        .ok()?;
-                        visited.insert(point).then_some(point)
-                    }));
-                }
-            }
-        }
-
+    for (_, search_point) in grid.iter() {
+        let local_region = search::flood_fill(search_point, search::grid4(grid, |a, b| a == b))
+            .into_iter()
+            .map(|p| p + Point::new(1, 1))
+            .sorted()
+            .collect_vec();
         regions.insert(local_region);
     }
 
@@ -56,17 +32,17 @@ fn solve(grid: &Grid<u8>) -> Output {
         // find perimeter by growing by 1
         let mut grow_1 = HashSet::default();
         for point in region {
-            grow_1.extend(DIRECTIONS.iter().filter_map(|direction| {
-                let point = Point::new(point.x as i64 + direction.x, point.y as i64 + direction.y)
-                    .try_into()
-                    .ok()?;
-                Some((point, *direction))
+            grow_1.extend(Direction::ALL.iter().filter_map(|direction| {
+                Some((
+                    point.checked_offset(direction.offset())?,
+                    direction.offset(),
+                ))
             }));
         }
 
         let edge_points: Vec<(Point<i64>, Point<i64>)> = grow_1
             .iter()
-            .filter(|(a, _)| !region.contains(&a))
+            .filter(|(a, _)| !region.contains(a))
             .map(|(a, b)| ((*a).try_into().unwrap(), *b))
             .sorted()
             .collect::<Vec<_>>();
@@ -80,7 +56,9 @@ fn solve(grid: &Grid<u8>) -> Output {
             for edge in edges.iter_mut() {
                 for (point_b, normal_b) in edge.clone().iter() {
                     if normal_a == *normal_b
-                        && (DIRECTIONS.iter().any(|n| *n + point_a == *point_b))
+                        && (Direction::ALL
+                            .iter()
+                            .any(|n| n.offset() + point_a == *point_b))
                     {
                         edge.push((point_a, normal_a));
                         i += 1;
@@ -103,14 +81,12 @@ fn solve(grid: &Grid<u8>) -> Output {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let grid = parse_input(input);
-    solve(&grid).perimeter_price
+pub fn part1(grid: &Grid<u8>) -> impl Display {
+    solve(grid).perimeter_price
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let grid = parse_input(input);
-    solve(&grid).side_price
+pub fn part2(grid: &Grid<u8>) -> impl Display {
+    solve(grid).side_price
 }
 
 #[cfg(test)]
@@ -144,25 +120,25 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE_1).to_string(), "140");
-        assert_eq!(part1(EXAMPLE_2).to_string(), "1930");
+        assert_eq!(part1(&parse_input(EXAMPLE_1)).to_string(), "140");
+        assert_eq!(part1(&parse_input(EXAMPLE_2)).to_string(), "1930");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "1396298");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE_1).to_string(), "80");
-        assert_eq!(part2(EXAMPLE_2).to_string(), "1206");
+        assert_eq!(part2(&parse_input(EXAMPLE_1)).to_string(), "80");
+        assert_eq!(part2(&parse_input(EXAMPLE_2)).to_string(), "1206");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "853588");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
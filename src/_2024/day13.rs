@@ -1,7 +1,10 @@
 use crate::prelude::*;
+use crate::util::linalg::{self, LinalgError};
+
+crate::solution!(2024, 13, "Claw Contraption", parse_input -> Vec<Machine>);
 
 #[derive(Debug, Clone)]
-struct Machine {
+pub struct Machine {
     a: Point<i64>,
     b: Point<i64>,
     d: Point<i64>,
@@ -29,52 +32,60 @@ fn parse_input(input: &str) -> Vec<Machine> {
 fn find_min_c(machine: &Machine) -> Option<i64> {
     let Machine { a, b, d } = machine;
 
-    // Check if b.x is zero (would cause division by zero)
-    if b.x == 0 {
-        return None;
+    match linalg::solve_integer([[a.x, b.x], [a.y, b.y]], [d.x, d.y]) {
+        // Buttons can't be pressed a negative number of times
+        Ok([a_count, b_count]) => (a_count >= 0 && b_count >= 0).then_some(3 * a_count + b_count),
+        // Both buttons move the claw along the same line, so many presses may reach the prize
+        Err(LinalgError::Underdetermined) => find_min_c_collinear(machine),
+        Err(_) => None,
     }
+}
 
-    // Calculate numerator and denominator for a
-    let numerator = d.y * b.x - d.x * b.y;
-    let denominator = a.y * b.x - a.x * b.y;
+fn find_min_c_collinear(machine: &Machine) -> Option<i64> {
+    let Machine { a, b, d } = machine;
 
-    // Check if denominator is zero (equations are linearly dependent)
-    if denominator == 0 {
-        return None;
+    // Solve along whichever axis the buttons move in, the other is checked at the end
+    let (along_a, along_b, along_d) = if a.x != 0 || b.x != 0 {
+        (a.x, b.x, d.x)
+    } else {
+        (a.y, b.y, d.y)
+    };
+    let presses = linalg::solve_diophantine(along_a, along_b, along_d)?;
+
+    // The range of k where neither count is negative
+    let (mut low, mut high) = (i64::MIN, i64::MAX);
+    for (start, step) in [(presses.x, presses.dx), (presses.y, presses.dy)] {
+        match step.signum() {
+            1 => low = low.max(-start.div_euclid(step)),
+            -1 => high = high.min(start.div_euclid(-step)),
+            _ if start < 0 => return None,
+            _ => {}
+        }
     }
-
-    // Check if we can get an integer solution for a
-    if numerator % denominator != 0 {
+    if low > high {
         return None;
     }
 
-    let a_count = numerator / denominator;
-
-    // Calculate numerator for b
-    let numerator_b = d.x - a_count * a.x;
-
-    // Check if we can get an integer solution for b
-    if numerator_b % b.x != 0 {
-        return None;
-    }
-
-    let b_count = numerator_b / b.x;
-
-    // Calculate minimum
-    Some(3 * a_count + b_count)
+    // The cost changes linearly with k, so the cheapest is at one end of the range
+    let k = if 3 * presses.dx + presses.dy > 0 || high == i64::MAX {
+        low
+    } else {
+        high
+    };
+    let (a_count, b_count) = presses.at(k);
+    (a.x * a_count + b.x * b_count == d.x && a.y * a_count + b.y * b_count == d.y)
+        .then_some(3 * a_count + b_count)
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let machines = parse_input(input);
+pub fn part1(machines: &[Machine]) -> impl Display {
     machines
         .iter()
-        .map(|m| find_min_c(&m).unwrap_or(0i64))
+        .map(|m| find_min_c(m).unwrap_or(0i64))
         .sum::<i64>()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(machines: &[Machine]) -> impl Display {
     let offset = 10000000000000;
-    let machines = parse_input(input);
     machines
         .iter()
         .cloned()
@@ -114,23 +125,47 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "480");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "480");
+    }
+
+    #[test]
+    fn collinear_buttons() {
+        let machines = parse_input(indoc! {"
+            Button A: X+2, Y+4
+            Button B: X+1, Y+2
+            Prize: X=10, Y=20
+
+            Button A: X+4, Y+8
+            Button B: X+1, Y+2
+            Prize: X=10, Y=20
+
+            Button A: X+2, Y+4
+            Button B: X+4, Y+8
+            Prize: X=5, Y=10
+
+            Button A: X+2, Y+4
+            Button B: X+1, Y+2
+            Prize: X=10, Y=21
+        "});
+        let costs = machines.iter().map(find_min_c).collect::<Vec<_>>();
+        // B alone is cheapest for the first, as many A presses as fit for the second
+        assert_eq!(costs, [Some(10), Some(8), None, None]);
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "32026");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "875318608908");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "875318608908");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "89013607072065");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
@@ -15,7 +15,7 @@ pub use day05 as day5;
 pub use day06 as day6;
 pub use day07 as day7;
 pub use day08 as day8;
-pub use day08 as day9;
+pub use day09 as day9;
 pub mod day10;
 pub mod day11;
 pub mod day12;
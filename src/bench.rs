@@ -0,0 +1,346 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Solver;
+use crate::util::store::project_path;
+
+/// Baselines depend on the machine they were measured on, so they're kept out of the repo.
+pub const BASELINE_FILE: &str = "data/bench.json";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Runs made before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+    /// How much slower than the baseline's median a run may be before it's a regression,
+    /// `0.2` allows 20%.
+    pub threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 3,
+            iterations: 10,
+            threshold: 0.2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        Stats {
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            median,
+            min: sorted[0],
+        }
+    }
+}
+
+/// The timings of a day, with parsing measured separately from each part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Stats,
+    pub parts: BTreeMap<u32, Stats>,
+}
+
+/// Which timing of a day got slower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BenchError {
+    #[error("{}: {source}", .path.display())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Runs the parts of a day `options.iterations` times after warming up.
+pub fn bench(solver: &Solver, input: &str, parts: &[u32], options: &Options) -> Measurement {
+    for _ in 0..options.warmup {
+        (solver.run)(input, parts);
+    }
+
+    let mut parse = vec![];
+    let mut samples = BTreeMap::<u32, Vec<Duration>>::new();
+    for _ in 0..options.iterations.max(1) {
+        let run = (solver.run)(input, parts);
+        parse.push(run.parse_time);
+        for answer in run.answers {
+            samples.entry(answer.part).or_default().push(answer.elapsed);
+        }
+    }
+
+    Measurement {
+        year: solver.year,
+        day: solver.day,
+        parse: Stats::from_samples(&parse),
+        parts: samples
+            .into_iter()
+            .map(|(part, samples)| (part, Stats::from_samples(&samples)))
+            .collect(),
+    }
+}
+
+/// Previously saved measurements, at most one per day.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    /// Loads `data/bench.json` from the crate root.
+    pub fn load() -> Result<Baseline, BenchError> {
+        Baseline::load_from(Self::default_path())
+    }
+
+    /// Loads a baseline from `path`, a missing file is an empty baseline.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Baseline, BenchError> {
+        let path = path.as_ref();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(e) => return Err(e.into()),
+        };
+        serde_json::from_str(&text).map_err(|source| BenchError::Json {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), BenchError> {
+        self.save_to(Self::default_path())
+    }
+
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<(), BenchError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(|source| BenchError::Json {
+            path: path.to_owned(),
+            source,
+        })?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    fn default_path() -> PathBuf {
+        project_path(BASELINE_FILE)
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.year == year && m.day == day)
+    }
+
+    /// Replaces the measurement of the same day, keeping parts which weren't run this time.
+    pub fn update(&mut self, measurement: Measurement) {
+        match self
+            .measurements
+            .iter_mut()
+            .find(|m| m.year == measurement.year && m.day == measurement.day)
+        {
+            Some(old) => {
+                old.parse = measurement.parse;
+                old.parts.extend(measurement.parts);
+            }
+            None => {
+                self.measurements.push(measurement);
+                self.measurements.sort_by_key(|m| (m.year, m.day));
+            }
+        }
+    }
+
+    /// The stages of a measurement whose median is slower than the baseline by more than the
+    /// threshold. Stages missing from the baseline are never regressions.
+    pub fn regressions(&self, measurement: &Measurement, threshold: f64) -> Vec<Regression> {
+        let Some(old) = self.get(measurement.year, measurement.day) else {
+            return vec![];
+        };
+
+        let stages = std::iter::once((Stage::Parse, &old.parse, &measurement.parse)).chain(
+            measurement
+                .parts
+                .iter()
+                .filter_map(|(part, new)| Some((Stage::Part(*part), old.parts.get(part)?, new))),
+        );
+        stages
+            .filter(|(_, old, new)| {
+                new.median.as_secs_f64() > old.median.as_secs_f64() * (1.0 + threshold)
+            })
+            .map(|(stage, old, new)| Regression {
+                stage,
+                before: old.median,
+                after: new.median,
+            })
+            .collect()
+    }
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            mean: ms(median),
+            median: ms(median),
+            min: ms(median),
+        }
+    }
+
+    #[test]
+    fn summarise_samples() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(stats.mean, ms(3));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(10), ms(2)]);
+        assert_eq!(stats.mean, Duration::from_micros(4250));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+    }
+
+    #[test]
+    fn bench_registered_day() {
+        let solver = registry::find(Some(2024), Some(1)).next().unwrap();
+        let options = Options {
+            warmup: 1,
+            iterations: 5,
+            ..Options::default()
+        };
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let measurement = bench(solver, input, &[2], &options);
+        assert_eq!((measurement.year, measurement.day), (2024, 1));
+        assert_eq!(measurement.parts.keys().collect::<Vec<_>>(), [&2]);
+        assert!(measurement.parse.min <= measurement.parse.median);
+    }
+
+    #[test]
+    fn flag_regressions() {
+        let mut baseline = Baseline::default();
+        baseline.update(Measurement {
+            year: 2023,
+            day: 5,
+            parse: stats(10),
+            parts: BTreeMap::from([(1, stats(100))]),
+        });
+
+        let measurement = Measurement {
+            year: 2023,
+            day: 5,
+            parse: stats(11),
+            parts: BTreeMap::from([(1, stats(150)), (2, stats(1000))]),
+        };
+        assert_eq!(
+            baseline.regressions(&measurement, 0.2),
+            [Regression {
+                stage: Stage::Part(1),
+                before: ms(100),
+                after: ms(150),
+            }]
+        );
+        assert!(baseline.regressions(&measurement, 0.5).is_empty());
+
+        let other_day = Measurement {
+            day: 6,
+            ..measurement
+        };
+        assert!(baseline.regressions(&other_day, 0.0).is_empty());
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bench.json");
+        assert!(Baseline::load_from(&path).unwrap().measurements.is_empty());
+
+        let mut baseline = Baseline::default();
+        for (day, part) in [(4, 1), (1, 1), (4, 2)] {
+            baseline.update(Measurement {
+                year: 2015,
+                day,
+                parse: stats(1),
+                parts: BTreeMap::from([(part, stats(day.into()))]),
+            });
+        }
+        baseline.save_to(&path).unwrap();
+
+        let loaded = Baseline::load_from(&path).unwrap();
+        assert_eq!(loaded.measurements.len(), 2);
+        assert_eq!(loaded.measurements[0].day, 1);
+        assert_eq!(
+            loaded.get(2015, 4).unwrap().parts,
+            BTreeMap::from([(1, stats(4)), (2, stats(4))])
+        );
+
+        std::fs::write(&path, "{").unwrap();
+        assert!(matches!(
+            Baseline::load_from(&path),
+            Err(BenchError::Json { .. })
+        ));
+    }
+}
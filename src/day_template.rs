@@ -1,16 +1,16 @@
 use crate::prelude::*;
 
+crate::solution!(2023, 1, "Title", parse_input -> String);
+
 fn parse_input(input: &str) -> String {
     input.to_owned()
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let _ = parse_input(input);
+pub fn part1(_input: &str) -> impl Display {
     0
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let _ = parse_input(input);
+pub fn part2(_input: &str) -> impl Display {
     0
 }
 
@@ -32,23 +32,23 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE).to_string(), "0");
+        assert_eq!(part1(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part1_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part1(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 1, part1(&parse_input(&input)));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE).to_string(), "0");
+        assert_eq!(part2(&parse_input(EXAMPLE)).to_string(), "0");
     }
 
     #[tokio::test]
     async fn part2_solve() {
         let input = util::input(YEAR, DAY).await;
-        assert_eq!(part2(&input).to_string(), "0");
+        util::check_answer(YEAR, DAY, 2, part2(&parse_input(&input)));
     }
 }
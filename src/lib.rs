@@ -6,9 +6,13 @@ pub mod _2021;
 pub mod _2022;
 pub mod _2023;
 pub mod _2024;
+pub mod bench;
 #[allow(unused_variables)]
 pub mod day_template;
 pub mod prelude;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
 pub mod util;
 
 pub use _2024::*;
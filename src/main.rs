@@ -0,0 +1,440 @@
+use advent::bench::{self, Baseline, Stage, Stats};
+use advent::registry::{self, Solver};
+use advent::scaffold::Scaffold;
+use advent::util::{
+    self,
+    answers::{Answers, Expected, Status},
+    puzzle,
+    store::HttpStore,
+    submit::{self, Guesses, Hint, Verdict},
+};
+use clap::{Parser, Subcommand};
+use itertools::Itertools;
+
+/// Run Advent of Code solutions.
+#[derive(Parser)]
+#[command(
+    name = "advent",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+    #[arg(required = true)]
+    year: Option<u32>,
+    day: Option<u32>,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check solutions against the answers recorded in answers.txt
+    Verify { year: Option<u32>, day: Option<u32> },
+    /// Submit an answer, running the solution when no answer is given
+    Submit {
+        year: u32,
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        answer: Option<String>,
+    },
+    /// Show the title, examples and example answers from a puzzle's description
+    Puzzle { year: u32, day: u32 },
+    /// Time solutions, flagging regressions against the saved baseline
+    Bench {
+        year: Option<u32>,
+        day: Option<u32>,
+        /// Runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Percentage slowdown of the median counted as a regression
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+        /// Save the results as the new baseline
+        #[arg(long)]
+        save: bool,
+    },
+    /// Create a day from the template, filled in from its puzzle description
+    Add {
+        year: u32,
+        day: u32,
+        /// Don't download the puzzle description
+        #[arg(long)]
+        no_fetch: bool,
+    },
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    let ok = match cli.command {
+        Some(Command::Verify { year, day }) => verify(year, day).await,
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        }) => submit(year, day, part, answer).await,
+        Some(Command::Puzzle { year, day }) => show_puzzle(year, day).await,
+        Some(Command::Bench {
+            year,
+            day,
+            warmup,
+            iterations,
+            threshold,
+            save,
+        }) => {
+            let options = bench::Options {
+                warmup,
+                iterations,
+                threshold: threshold / 100.0,
+            };
+            run_bench(year, day, options, save).await
+        }
+        Some(Command::Add {
+            year,
+            day,
+            no_fetch,
+        }) => add(year, day, no_fetch).await,
+        None => run(cli.run).await,
+    };
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn find_solvers(year: Option<u32>, day: Option<u32>) -> Vec<&'static Solver> {
+    let solvers = registry::find(year, day).collect_vec();
+    if solvers.is_empty() {
+        eprintln!("No solutions registered for {}", describe(year, day));
+        std::process::exit(1);
+    }
+    solvers
+}
+
+async fn run(args: RunArgs) -> bool {
+    let solvers = find_solvers(args.year, args.day);
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut ok = true;
+    for solver in solvers {
+        println!("{} day {}: {}", solver.year, solver.day, solver.title);
+
+        let input = match util::try_input(solver.year, solver.day).await {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("  {e}");
+                ok = false;
+                continue;
+            }
+        };
+        let run = (solver.run)(&input, &parts);
+        println!("  parse: {:?}", run.parse_time);
+        for answer in run.answers {
+            println!(
+                "  part {}: {} ({:?})",
+                answer.part, answer.answer, answer.elapsed
+            );
+        }
+    }
+    ok
+}
+
+async fn verify(year: Option<u32>, day: Option<u32>) -> bool {
+    let solvers = find_solvers(year, day);
+    let answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing, mut unsolved, mut errors) = (0, 0, 0, 0, 0);
+    for solver in solvers {
+        let (year, day) = (solver.year, solver.day);
+
+        // Unsolved parts aren't worth running
+        let parts = [1, 2]
+            .into_iter()
+            .filter(|&part| {
+                if answers.get(year, day, part) == Some(&Expected::Unsolved) {
+                    println!("{year} day {day} part {part}: unsolved");
+                    unsolved += 1;
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect_vec();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let input = match util::try_input(year, day).await {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{year} day {day}: {e}");
+                errors += parts.len();
+                continue;
+            }
+        };
+
+        let run = (solver.run)(&input, &parts);
+        for answer in run.answers {
+            let part = answer.part;
+            match answers.check(year, day, part, &answer.answer) {
+                Status::Pass => {
+                    println!("{year} day {day} part {part}: pass ({:?})", answer.elapsed);
+                    passed += 1;
+                }
+                Status::Fail { expected } => {
+                    println!(
+                        "{year} day {day} part {part}: FAIL, expected {expected:?} but got {:?}",
+                        answer.answer
+                    );
+                    failed += 1;
+                }
+                Status::Missing => {
+                    println!(
+                        "{year} day {day} part {part}: missing, got {:?}",
+                        answer.answer
+                    );
+                    missing += 1;
+                }
+                Status::Unsolved => {
+                    println!("{year} day {day} part {part}: unsolved");
+                    unsolved += 1;
+                }
+            }
+        }
+    }
+
+    print!("{passed} passed, {failed} failed, {missing} missing, {unsolved} unsolved");
+    if errors > 0 {
+        print!(", {errors} without input");
+    }
+    println!();
+
+    failed == 0 && errors == 0
+}
+
+async fn submit(year: u32, day: u32, part: u32, answer: Option<String>) -> bool {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = find_solvers(Some(year), Some(day))[0];
+            let input = match util::try_input(year, day).await {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return false;
+                }
+            };
+            let mut run = (solver.run)(&input, &[part]);
+            run.answers.remove(0).answer
+        }
+    };
+    println!("Submitting {answer:?} for {year} day {day} part {part}");
+
+    let result = match Guesses::load() {
+        Ok(mut guesses) => {
+            let http = HttpStore::from_env();
+            submit::submit(&http, &mut guesses, year, day, part, &answer).await
+        }
+        Err(e) => Err(e),
+    };
+    let verdict = match result {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    match verdict {
+        Verdict::Correct => {
+            println!("Correct");
+            let saved = Answers::load().and_then(|mut answers| {
+                answers.set(year, day, part, Expected::Answer(answer));
+                answers.save()
+            });
+            if let Err(e) = saved {
+                eprintln!("failed to record answer: {e}");
+                return false;
+            }
+            true
+        }
+        Verdict::Wrong { hint, wait } => {
+            match hint {
+                Some(Hint::TooHigh) => println!("Wrong, too high"),
+                Some(Hint::TooLow) => println!("Wrong, too low"),
+                None => println!("Wrong"),
+            }
+            if let Some(wait) = wait {
+                println!("Wait {wait:?} before trying again");
+            }
+            false
+        }
+        Verdict::TooSoon { wait } => {
+            println!("Too soon, wait {wait:?}");
+            false
+        }
+        Verdict::WrongLevel => {
+            println!("Not the right level, is it already solved?");
+            false
+        }
+    }
+}
+
+async fn show_puzzle(year: u32, day: u32) -> bool {
+    let puzzle = match puzzle::fetch(&HttpStore::from_env(), year, day).await {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    println!("{year} day {day}: {}", puzzle.title);
+    for (i, part) in puzzle.parts.iter().enumerate() {
+        println!("\npart {}", i + 1);
+        for example in &part.examples {
+            println!("{example}");
+        }
+        println!("answers: {}", part.answers.join(", "));
+    }
+    true
+}
+
+async fn run_bench(
+    year: Option<u32>,
+    day: Option<u32>,
+    options: bench::Options,
+    save: bool,
+) -> bool {
+    let solvers = find_solvers(year, day);
+    let mut baseline = match Baseline::load() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    let (mut regressed, mut errors) = (0, 0);
+    for solver in solvers {
+        println!("{} day {}: {}", solver.year, solver.day, solver.title);
+
+        let input = match util::try_input(solver.year, solver.day).await {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("  {e}");
+                errors += 1;
+                continue;
+            }
+        };
+
+        let measurement = bench::bench(solver, &input, &[1, 2], &options);
+        let regressions = baseline.regressions(&measurement, options.threshold);
+        let stages = std::iter::once((Stage::Parse, &measurement.parse)).chain(
+            measurement
+                .parts
+                .iter()
+                .map(|(&part, stats)| (Stage::Part(part), stats)),
+        );
+        for (stage, stats) in stages {
+            let name = match stage {
+                Stage::Parse => "parse".to_owned(),
+                Stage::Part(part) => format!("part {part}"),
+            };
+            print!("  {name}: {}", format_stats(stats));
+            if let Some(regression) = regressions.iter().find(|r| r.stage == stage) {
+                let change = regression.after.as_secs_f64() / regression.before.as_secs_f64();
+                print!(
+                    "  REGRESSED from {:?} (+{:.0}%)",
+                    regression.before,
+                    (change - 1.0) * 100.0
+                );
+            }
+            println!();
+        }
+
+        regressed += regressions.len();
+        baseline.update(measurement);
+    }
+
+    if save {
+        if let Err(e) = baseline.save() {
+            eprintln!("failed to save baseline: {e}");
+            return false;
+        }
+    }
+    if regressed > 0 {
+        println!("{regressed} regressed");
+    }
+
+    regressed == 0 && errors == 0
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "mean {:?}, median {:?}, min {:?}",
+        stats.mean, stats.median, stats.min
+    )
+}
+
+async fn add(year: u32, day: u32, no_fetch: bool) -> bool {
+    let puzzle = if no_fetch {
+        None
+    } else {
+        match puzzle::fetch(&HttpStore::from_env(), year, day).await {
+            Ok(puzzle) => Some(puzzle),
+            Err(e) => {
+                eprintln!("Couldn't fetch the puzzle, using the bare template: {e}");
+                None
+            }
+        }
+    };
+
+    match Scaffold::new(env!("CARGO_MANIFEST_DIR")).add(year, day, puzzle.as_ref()) {
+        Ok(changed) if changed.is_empty() => println!("{year} day {day} already exists"),
+        Ok(changed) => {
+            for path in changed {
+                println!("Updated {}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    }
+    true
+}
+
+fn describe(year: Option<u32>, day: Option<u32>) -> String {
+    let mut s = match year {
+        Some(year) => year.to_string(),
+        None => "any year".to_owned(),
+    };
+    if let Some(day) = day {
+        s.push_str(&format!(" day {day}"));
+    }
+    s
+}
@@ -2,7 +2,9 @@ pub use crate::{
     util::grid::Grid,
     util::input,
     util::math::{gcd, lcm},
-    util::point::Point,
+    util::point::{Dir8, Direction, Point},
+    util::point_n::{Point3, PointN},
+    util::sparse_grid::SparseGrid,
 };
 
 pub use indoc::{concatdoc, eprintdoc, formatdoc, indoc, printdoc};
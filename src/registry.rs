@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+
+use crate::solution::Solution;
+
+/// A single registered puzzle day.
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub run: fn(&str, &[u32]) -> Run,
+}
+
+/// The answers of a run, with the parse time reported separately from each part.
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Answer {
+    pub part: u32,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+fn run<S: Solution>(input: &str, parts: &[u32]) -> Run {
+    let start = Instant::now();
+    let input = S::parse(input);
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input).to_string(),
+                2 => S::part2(&input).to_string(),
+                _ => panic!("{} day {} has no part {part}", S::YEAR, S::DAY),
+            };
+            Answer {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Run {
+        parse_time,
+        answers,
+    }
+}
+
+macro_rules! registry {
+    ($($module: ident :: $day_module: ident,)*) => {
+        pub static SOLVERS: &[Solver] = &[
+            $(
+                Solver {
+                    year: <crate::$module::$day_module::Day>::YEAR,
+                    day: <crate::$module::$day_module::Day>::DAY,
+                    title: <crate::$module::$day_module::Day>::TITLE,
+                    run: run::<crate::$module::$day_module::Day>,
+                },
+            )*
+        ];
+    };
+}
+
+registry! {
+    _2015::day01,
+    _2015::day02,
+    _2015::day03,
+    _2015::day04,
+    _2015::day05,
+    _2015::day06,
+    _2015::day07,
+    _2015::day08,
+    _2015::day09,
+    _2015::day10,
+    _2015::day11,
+    _2015::day12,
+    _2015::day13,
+    _2015::day14,
+    _2015::day15,
+    _2015::day16,
+    _2015::day17,
+    _2015::day18,
+    _2015::day19,
+    _2015::day20,
+    _2015::day21,
+    _2016::day01,
+    _2019::day01,
+    _2019::day02,
+    _2019::day03,
+    _2019::day04,
+    _2020::day01,
+    _2020::day02,
+    _2020::day03,
+    _2020::day04,
+    _2021::day01,
+    _2021::day02,
+    _2021::day03,
+    _2021::day05,
+    _2021::day06,
+    _2021::day07,
+    _2021::day08,
+    _2021::day09,
+    _2021::day10,
+    _2021::day11,
+    _2021::day12,
+    _2021::day13,
+    _2022::day01,
+    _2022::day02,
+    _2022::day03,
+    _2023::day01,
+    _2023::day02,
+    _2023::day03,
+    _2023::day04,
+    _2023::day05,
+    _2023::day06,
+    _2023::day07,
+    _2023::day08,
+    _2023::day09,
+    _2023::day10,
+    _2023::day11,
+    _2023::day12,
+    _2023::day13,
+    _2024::day01,
+    _2024::day02,
+    _2024::day03,
+    _2024::day04,
+    _2024::day05,
+    _2024::day06,
+    _2024::day07,
+    _2024::day08,
+    _2024::day09,
+    _2024::day10,
+    _2024::day11,
+    _2024::day12,
+    _2024::day13,
+}
+
+/// Find every registered solver matching the filter, a `None` matches anything.
+pub fn find(year: Option<u32>, day: Option<u32>) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |solver| {
+        year.is_none_or(|year| solver.year == year) && day.is_none_or(|day| solver.day == day)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn solvers_are_unique_and_sorted() {
+        for (a, b) in SOLVERS.iter().tuple_windows() {
+            assert!(
+                (a.year, a.day) < (b.year, b.day),
+                "{} day {} is out of order",
+                b.year,
+                b.day
+            );
+        }
+    }
+
+    #[test]
+    fn find_solver() {
+        assert_eq!(find(Some(2024), None).count(), 13);
+
+        let solver = find(Some(2024), Some(1)).next().unwrap();
+        assert_eq!(solver.title, "Historian Hysteria");
+
+        let run = (solver.run)("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n", &[1, 2]);
+        let answers = run.answers.iter().map(|a| (a.part, a.answer.as_str()));
+        assert_eq!(answers.collect_vec(), [(1, "11"), (2, "31")]);
+
+        assert!(find(Some(2014), None).next().is_none());
+    }
+}
@@ -0,0 +1,334 @@
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::util::{puzzle, puzzle::Puzzle, store};
+
+const TEMPLATE_PATH: &str = "src/day_template.rs";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScaffoldError {
+    #[error("{year} day {day} is not an Advent of Code puzzle")]
+    InvalidDate { year: u32, day: u32 },
+    #[error("{}: no {what} to insert next to", .path.display())]
+    MissingAnchor { path: PathBuf, what: &'static str },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Adds new days to the source tree rooted at `root`.
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Scaffold { root: root.into() }
+    }
+
+    /// Creates the day's module from the template and registers it everywhere it's needed,
+    /// returning the files which changed. Anything already in place is left alone.
+    ///
+    /// Every edit is worked out before anything is written, so a missing anchor leaves the
+    /// tree untouched.
+    pub fn add(
+        &self,
+        year: u32,
+        day: u32,
+        puzzle: Option<&Puzzle>,
+    ) -> Result<Vec<PathBuf>, ScaffoldError> {
+        if store::validate(year, day).is_err() {
+            return Err(ScaffoldError::InvalidDate { year, day });
+        }
+
+        let mut writes = vec![];
+
+        let day_path = self.root.join(format!("src/_{year}/day{day:02}.rs"));
+        if !day_path.exists() {
+            let template = std::fs::read_to_string(self.root.join(TEMPLATE_PATH))?;
+            let mut source = render_template(&template, year, day);
+            if let Some(puzzle) = puzzle {
+                source = puzzle::fill_template(&source, puzzle);
+            }
+            writes.push((day_path, source));
+        }
+
+        let mod_path = self.root.join(format!("src/_{year}/mod.rs"));
+        writes.extend(edit(mod_path, |text| ensure_year_mod(text, day))?);
+
+        let lib_path = self.root.join("src/lib.rs");
+        writes.extend(edit(lib_path, |text| {
+            ensure_sorted_line(text, &format!("pub mod _{year};"), &LIB_MOD, Some(0))
+                .ok_or("year module")
+        })?);
+
+        let registry_path = self.root.join("src/registry.rs");
+        writes.extend(edit(registry_path, |text| {
+            let entry = format!("    _{year}::day{day:02},");
+            ensure_sorted_line(text, &entry, &REGISTRY_ENTRY, None).ok_or("registry entry")
+        })?);
+
+        let mut changed = vec![];
+        for (path, text) in writes {
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            std::fs::write(&path, text)?;
+            changed.push(path);
+        }
+        Ok(changed)
+    }
+}
+
+/// Edits a file, which may not exist yet, returning its new text if it changed.
+fn edit(
+    path: PathBuf,
+    change: impl FnOnce(&str) -> Result<String, &'static str>,
+) -> Result<Option<(PathBuf, String)>, ScaffoldError> {
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let new = change(&text).map_err(|what| ScaffoldError::MissingAnchor {
+        path: path.clone(),
+        what,
+    })?;
+    Ok((new != text).then_some((path, new)))
+}
+
+static LIB_MOD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^pub mod _\d{4};$").unwrap());
+static REGISTRY_ENTRY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^    _\d{4}::day\d{2},$").unwrap());
+static DAY_MOD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^pub mod day\d{2};$").unwrap());
+static DAY_ALIAS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^pub use day(\d{2}) as day(\d);$").unwrap());
+
+pub fn render_template(template: &str, year: u32, day: u32) -> String {
+    static SOLUTION: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"crate::solution!\(\d+, \d+, ").unwrap());
+    static YEAR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"const YEAR: u32 = \d+;").unwrap());
+    static DAY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"const DAY: u32 = \d+;").unwrap());
+
+    let source = SOLUTION.replace(template, format!("crate::solution!({year}, {day}, "));
+    let source = YEAR.replace(&source, format!("const YEAR: u32 = {year};"));
+    DAY.replace(&source, format!("const DAY: u32 = {day};"))
+        .into_owned()
+}
+
+/// Adds `pub mod dayNN;` and, for single digit days, the `pub use dayNN as dayN;` alias,
+/// correcting an alias which points at the wrong module.
+fn ensure_year_mod(text: &str, day: u32) -> Result<String, &'static str> {
+    let mut text = ensure_sorted_line(text, &format!("pub mod day{day:02};"), &DAY_MOD, None)
+        .ok_or("day module")?;
+
+    if day < 10 {
+        let alias = format!("pub use day{day:02} as day{day};");
+        let mut lines = text.lines().map(str::to_owned).collect::<Vec<_>>();
+        for line in &mut lines {
+            if DAY_ALIAS
+                .captures(line)
+                .is_some_and(|c| c[2].parse() == Ok(day))
+            {
+                line.clone_from(&alias);
+            }
+        }
+
+        // Aliases go after the single digit day modules when there are none yet
+        let after = lines
+            .iter()
+            .rposition(|line| DAY_MOD.is_match(line) && line.as_str() < "pub mod day10;")
+            .map_or(0, |i| i + 1);
+        text = lines.into_iter().map(|line| line + "\n").collect();
+        text = ensure_sorted_line(&text, &alias, &DAY_ALIAS, Some(after)).ok_or("day alias")?;
+    }
+
+    Ok(text)
+}
+
+/// Inserts `line` in order among the lines matching `kind`, or at line `fallback` when there
+/// are none. Returns `None` when there's nowhere sensible to put it.
+fn ensure_sorted_line(
+    text: &str,
+    line: &str,
+    kind: &Regex,
+    fallback: Option<usize>,
+) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        return Some(text.to_owned());
+    }
+
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| kind.is_match(l))
+        .collect::<Vec<_>>();
+    let index = match matching.iter().rev().find(|(_, &l)| l < line) {
+        Some(&(i, _)) => i + 1,
+        None => match matching.first() {
+            Some(&(i, _)) => i,
+            None => match fallback {
+                Some(i) => i.min(lines.len()),
+                None if lines.is_empty() => 0,
+                None => return None,
+            },
+        },
+    };
+
+    lines.insert(index, line);
+    Some(lines.into_iter().map(|line| format!("{line}\n")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = indoc::indoc! {"
+        pub mod _2015;
+        pub mod _2024;
+        #[allow(unused_variables)]
+        pub mod day_template;
+        pub mod registry;
+    "};
+
+    const REGISTRY: &str = indoc::indoc! {"
+        registry! {
+            _2015::day01,
+            _2024::day01,
+            _2024::day09,
+        }
+    "};
+
+    const MOD_2024: &str = indoc::indoc! {"
+        pub mod day01;
+        pub mod day09;
+        pub use day01 as day1;
+        pub use day08 as day9;
+    "};
+
+    fn tree() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(src.join("_2024")).unwrap();
+        std::fs::write(src.join("lib.rs"), LIB).unwrap();
+        std::fs::write(src.join("registry.rs"), REGISTRY).unwrap();
+        std::fs::write(src.join("_2024/mod.rs"), MOD_2024).unwrap();
+        std::fs::write(src.join("day_template.rs"), include_str!("day_template.rs")).unwrap();
+        dir
+    }
+
+    fn read(dir: &tempfile::TempDir, path: &str) -> String {
+        std::fs::read_to_string(dir.path().join(path)).unwrap()
+    }
+
+    #[test]
+    fn add_day_to_existing_year() {
+        let dir = tree();
+        let scaffold = Scaffold::new(dir.path());
+
+        let changed = scaffold.add(2024, 5, None).unwrap();
+        assert_eq!(changed.len(), 3);
+        assert_eq!(
+            read(&dir, "src/_2024/mod.rs"),
+            indoc::indoc! {"
+                pub mod day01;
+                pub mod day05;
+                pub mod day09;
+                pub use day01 as day1;
+                pub use day05 as day5;
+                pub use day08 as day9;
+            "}
+        );
+        assert_eq!(
+            read(&dir, "src/registry.rs"),
+            indoc::indoc! {"
+                registry! {
+                    _2015::day01,
+                    _2024::day01,
+                    _2024::day05,
+                    _2024::day09,
+                }
+            "}
+        );
+        assert_eq!(read(&dir, "src/lib.rs"), LIB);
+
+        let day = read(&dir, "src/_2024/day05.rs");
+        assert!(day.contains(r#"crate::solution!(2024, 5, "Title", parse_input -> String);"#));
+        assert!(day.contains("const YEAR: u32 = 2024;"));
+        assert!(day.contains("const DAY: u32 = 5;"));
+
+        // Running it again changes nothing
+        assert!(scaffold.add(2024, 5, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn add_fixes_wrong_alias() {
+        let dir = tree();
+        std::fs::write(dir.path().join("src/_2024/day09.rs"), "").unwrap();
+        let changed = Scaffold::new(dir.path()).add(2024, 9, None).unwrap();
+        assert_eq!(changed, vec![dir.path().join("src/_2024/mod.rs")]);
+        assert!(read(&dir, "src/_2024/mod.rs").contains("pub use day09 as day9;\n"));
+        assert!(!read(&dir, "src/_2024/mod.rs").contains("day08"));
+        assert_eq!(read(&dir, "src/_2024/day09.rs"), "");
+    }
+
+    #[test]
+    fn add_new_year() {
+        let dir = tree();
+        let scaffold = Scaffold::new(dir.path());
+        let puzzle = Puzzle {
+            title: "Secret Entrance".to_owned(),
+            parts: vec![],
+        };
+
+        scaffold.add(2016, 12, Some(&puzzle)).unwrap();
+        assert_eq!(read(&dir, "src/_2016/mod.rs"), "pub mod day12;\n");
+        assert!(read(&dir, "src/lib.rs")
+            .starts_with("pub mod _2015;\npub mod _2016;\npub mod _2024;\n"));
+        assert!(read(&dir, "src/registry.rs").contains("    _2015::day01,\n    _2016::day12,\n"));
+        assert!(read(&dir, "src/_2016/day12.rs")
+            .contains(r#"crate::solution!(2016, 12, "Secret Entrance", "#));
+
+        scaffold.add(2016, 3, None).unwrap();
+        assert_eq!(
+            read(&dir, "src/_2016/mod.rs"),
+            "pub mod day03;\npub use day03 as day3;\npub mod day12;\n"
+        );
+
+        scaffold.add(2026, 1, None).unwrap();
+        assert!(read(&dir, "src/lib.rs").contains("pub mod _2024;\npub mod _2026;\n"));
+        assert!(
+            read(&dir, "src/registry.rs").ends_with("    _2024::day09,\n    _2026::day01,\n}\n")
+        );
+        assert!(scaffold.add(2026, 1, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn add_validates_date() {
+        let dir = tree();
+        let scaffold = Scaffold::new(dir.path());
+        for (year, day) in [(2014, 1), (2024, 0), (2024, 26)] {
+            assert!(matches!(
+                scaffold.add(year, day, None),
+                Err(ScaffoldError::InvalidDate { .. })
+            ));
+        }
+        assert_eq!(read(&dir, "src/lib.rs"), LIB);
+    }
+
+    #[test]
+    fn add_without_registry_entries() {
+        let dir = tree();
+        std::fs::write(dir.path().join("src/registry.rs"), "registry! {\n}\n").unwrap();
+        assert!(matches!(
+            Scaffold::new(dir.path()).add(2024, 2, None),
+            Err(ScaffoldError::MissingAnchor {
+                what: "registry entry",
+                ..
+            })
+        ));
+        // Nothing is written when any file can't be updated
+        assert!(!dir.path().join("src/_2024/day02.rs").exists());
+        assert!(!read(&dir, "src/_2024/mod.rs").contains("day02"));
+    }
+}
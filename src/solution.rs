@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+/// A single day's puzzle, implemented for each day module by [`solution!`](crate::solution!).
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+
+    fn part2(input: &Self::Input<'_>) -> impl Display;
+}
+
+/// Implements [`Solution`] for a day module as `Day`, forwarding to the module's
+/// `part1` and `part2` functions. Days which parse their input name the parse function
+/// and the type it returns, both parts are then given a reference to the parsed value.
+///
+/// ```ignore
+/// crate::solution!(2015, 1, "Not Quite Lisp");
+/// crate::solution!(2024, 5, "Print Queue", parse_input -> Manual);
+/// crate::solution!(2015, 5, "Doesn't He Have Intern-Elves For This?", parse_input -> Vec<&'a str>);
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($year: literal, $day: literal, $title: literal) => {
+        $crate::solution!(@impl $year, $day, $title, std::convert::identity; &'a str);
+    };
+    ($year: literal, $day: literal, $title: literal, $parse: ident -> $input: ty) => {
+        $crate::solution!(@impl $year, $day, $title, $parse; $input);
+    };
+    (@impl $year: literal, $day: literal, $title: literal, $parse: expr; $input: ty) => {
+        pub struct Day;
+
+        impl $crate::solution::Solution for Day {
+            const YEAR: u32 = $year;
+            const DAY: u32 = $day;
+            const TITLE: &'static str = $title;
+
+            type Input<'a> = $input;
+
+            fn parse(input: &str) -> Self::Input<'_> {
+                $parse(input)
+            }
+
+            fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+                part1(input)
+            }
+
+            fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+                part2(input)
+            }
+        }
+    };
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use super::store::project_path;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+const HEADER: &str = "\
+# Answers for each part as `year day part answer`, newlines in an answer are written as `\\n`.
+# Parts which aren't solved yet are marked with `?`.
+";
+
+/// What is recorded for a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    Unsolved,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer is recorded for the part.
+    Missing,
+    /// The part is recorded as not solved yet.
+    Unsolved,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersError {
+    #[error("{path}:{line}: expected `year day part answer`")]
+    Malformed { path: PathBuf, line: usize },
+    #[error("{path}:{line}: {year} day {day} part {part} is recorded twice")]
+    Duplicate {
+        path: PathBuf,
+        line: usize,
+        year: u32,
+        day: u32,
+        part: u32,
+    },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The checked-in answers, keyed by year, day and part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(u32, u32, u32), Expected>,
+}
+
+impl Answers {
+    /// Loads `answers.txt` from the crate root.
+    pub fn load() -> Result<Answers, AnswersError> {
+        Answers::load_from(project_path(ANSWERS_FILE))
+    }
+
+    /// Loads answers from `path`, a missing file has no answers.
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Answers, AnswersError> {
+        let path = path.into();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || AnswersError::Malformed {
+                path: path.clone(),
+                line: i + 1,
+            };
+            let mut fields = line.splitn(4, ' ');
+            let mut number = || -> Result<u32, AnswersError> {
+                fields
+                    .next()
+                    .and_then(|f| f.parse().ok())
+                    .ok_or_else(malformed)
+            };
+            let (year, day, part) = (number()?, number()?, number()?);
+            let expected = match fields.next().ok_or_else(malformed)? {
+                "?" => Expected::Unsolved,
+                answer => Expected::Answer(answer.replace("\\n", "\n")),
+            };
+
+            if answers.insert((year, day, part), expected).is_some() {
+                return Err(AnswersError::Duplicate {
+                    path,
+                    line: i + 1,
+                    year,
+                    day,
+                    part,
+                });
+            }
+        }
+
+        Ok(Answers { path, answers })
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&Expected> {
+        self.answers.get(&(year, day, part))
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, part: u32, expected: Expected) {
+        self.answers.insert((year, day, part), expected);
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Status {
+        match self.get(year, day, part) {
+            None => Status::Missing,
+            Some(Expected::Unsolved) => Status::Unsolved,
+            Some(Expected::Answer(expected)) if expected == answer => Status::Pass,
+            Some(Expected::Answer(expected)) => Status::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    /// Writes the answers back to the file they were loaded from, sorted by year, day and part.
+    pub fn save(&self) -> Result<(), AnswersError> {
+        let mut text = HEADER.to_owned();
+        for (&(year, day, part), expected) in &self.answers {
+            let answer = match expected {
+                Expected::Answer(answer) => answer.replace('\n', "\\n"),
+                Expected::Unsolved => "?".to_owned(),
+            };
+            text.push_str(&format!("{year} {day} {part} {answer}\n"));
+        }
+        std::fs::write(&self.path, text)?;
+        Ok(())
+    }
+}
+
+/// Asserts an answer matches `answers.txt`, used by each day's `_solve` tests.
+///
+/// Parts recorded as unsolved aren't checked.
+#[track_caller]
+pub fn check_answer(year: u32, day: u32, part: u32, answer: impl Display) {
+    let answers = Answers::load().unwrap();
+    let answer = answer.to_string();
+    match answers.check(year, day, part, &answer) {
+        Status::Pass | Status::Unsolved => {}
+        Status::Fail { expected } => assert_eq!(
+            answer, expected,
+            "wrong answer for {year} day {day} part {part}"
+        ),
+        Status::Missing => {
+            panic!("no answer recorded for {year} day {day} part {part}, got {answer:?}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ANSWERS_FILE);
+        std::fs::write(
+            &path,
+            "# comment\n2024 1 1 11\n2024 1 2 ?\n\n2021 13 2 #..#\\n#..#\n2015 12 1 with spaces\n",
+        )
+        .unwrap();
+
+        let mut answers = Answers::load_from(&path).unwrap();
+        assert_eq!(answers.check(2024, 1, 1, "11"), Status::Pass);
+        assert_eq!(
+            answers.check(2024, 1, 1, "12"),
+            Status::Fail {
+                expected: "11".to_owned()
+            }
+        );
+        assert_eq!(answers.check(2024, 1, 2, "31"), Status::Unsolved);
+        assert_eq!(answers.check(2024, 2, 1, "2"), Status::Missing);
+        assert_eq!(answers.check(2021, 13, 2, "#..#\n#..#"), Status::Pass);
+        assert_eq!(answers.check(2015, 12, 1, "with spaces"), Status::Pass);
+
+        answers.set(2024, 1, 2, Expected::Answer("31".to_owned()));
+        answers.save().unwrap();
+        let answers = Answers::load_from(&path).unwrap();
+        assert_eq!(answers.check(2024, 1, 2, "31"), Status::Pass);
+        assert_eq!(answers.check(2021, 13, 2, "#..#\n#..#"), Status::Pass);
+    }
+
+    #[test]
+    fn malformed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ANSWERS_FILE);
+
+        std::fs::write(&path, "2024 1 x 11\n").unwrap();
+        assert!(matches!(
+            Answers::load_from(&path),
+            Err(AnswersError::Malformed { line: 1, .. })
+        ));
+
+        std::fs::write(&path, "2024 1 1 11\n2024 1 1 12\n").unwrap();
+        assert!(matches!(
+            Answers::load_from(&path),
+            Err(AnswersError::Duplicate { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn checked_in_answers_load() {
+        let answers = Answers::load().unwrap();
+        assert_eq!(
+            answers.get(2024, 1, 1),
+            Some(&Expected::Answer("2113135".to_owned()))
+        );
+    }
+}
@@ -1,73 +1,94 @@
-use super::point::Point;
+use std::convert::Infallible;
 
-#[derive(Clone)]
+use super::point::{Dir8, Direction, Point};
+use super::render;
+
+#[derive(Clone, PartialEq, Eq)]
 pub struct Grid<T> {
     data: Vec<T>,
     width: usize,
     height: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GridError<E> {
+    #[error("line {line} has {found} cells but the first line has {expected}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("line {line} column {column}: {error}")]
+    Cell {
+        line: usize,
+        column: usize,
+        error: E,
+    },
+    #[error("line {line} column {column}: `{marker}` appears more than once")]
+    DuplicateMarker {
+        marker: char,
+        line: usize,
+        column: usize,
+    },
+    #[error("no `{0}` in the grid")]
+    MissingMarker(char),
+}
+
 impl Grid<char> {
+    /// Parses a grid of characters, ignoring whitespace around each line.
+    ///
+    /// Panics if the lines aren't all the same width.
     pub fn parse(input: &str) -> Self {
-        let mut data = vec![];
-        let mut width = 0;
-        let mut height = 0;
-        for line in input.lines() {
-            let line = line.trim();
-            width = line.len();
-            height += 1;
-            data.extend(line.chars());
-        }
-        Self {
-            data,
-            width,
-            height,
-        }
+        let trimmed = input.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+        Grid::parse_with(&trimmed, Ok::<_, Infallible>).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
 impl Grid<u8> {
+    /// Parses a grid of bytes.
+    ///
+    /// Panics if the lines aren't all the same width.
     pub fn parse_bytes(input: &str) -> Self {
-        let mut data = Vec::with_capacity(input.as_bytes().len());
+        let input = input.trim_end_matches(['\r', '\n']);
+        if input.is_empty() {
+            return Grid::from_fn(0, 0, |_| 0);
+        }
 
-        let mut width = 0;
+        let mut data = Vec::with_capacity(input.len());
+        let mut width = None;
         let mut height = 0;
-
-        for &b in input.as_bytes() {
-            if b == b'\n' {
-                height += 1;
-            } else {
-                if height == 0 {
-                    width += 1;
-                }
-
-                data.push(b);
+        for line in input.as_bytes().split(|&b| b == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            let width = *width.get_or_insert(line.len());
+            if line.len() != width {
+                let e = GridError::<Infallible>::Ragged {
+                    line: height + 1,
+                    expected: width,
+                    found: line.len(),
+                };
+                panic!("{e}");
             }
+            data.extend_from_slice(line);
+            height += 1;
         }
 
         Self {
             data,
-            width,
+            width: width.unwrap_or(0),
             height,
         }
     }
 
     pub fn display(&self) -> String {
-        let mut s = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let c = self[Point::new(x, y)];
-                s.push(if c == 0 {
-                    '.'
-                } else if c < 10 {
-                    (b'0' + c) as char
-                } else {
-                    c as char
-                });
+        render::text(self, |&c| {
+            if c == 0 {
+                '.'
+            } else if c < 10 {
+                (b'0' + c) as char
+            } else {
+                c as char
             }
-            s.push('\n');
-        }
-        s
+        })
     }
 }
 
@@ -88,6 +109,62 @@ impl<T> Grid<T>
 where
     T: Clone,
 {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring along the main diagonal.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| self[(p.y, p.x)].clone())
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_90(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[(p.y, self.height - 1 - p.x)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let mut data = self.data.clone();
+        data.reverse();
+        Self { data, ..*self }
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_270(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[(self.width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let mut flipped = self.clone();
+        for y in 0..self.height {
+            flipped[y].reverse();
+        }
+        flipped
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[(p.x, self.height - 1 - p.y)].clone()
+        })
+    }
+
+    /// Repeats the grid `across` times horizontally and `down` times vertically.
+    pub fn tile(&self, across: usize, down: usize) -> Self {
+        Grid::from_fn(self.width * across, self.height * down, |p| {
+            self[(p.x % self.width, p.y % self.height)].clone()
+        })
+    }
+
     pub fn insert_row(&mut self, y: usize, element: T) {
         self.data
             .splice(y * self.width..y * self.width, vec![element; self.width]);
@@ -103,6 +180,107 @@ where
 }
 
 impl<T> Grid<T> {
+    /// Parses each character into a cell, checking every line is the same width.
+    ///
+    /// Lines may end with `\r\n`, and the final newline is optional.
+    pub fn parse_with<E>(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, GridError<E>> {
+        Self::parse_cells(input, |c, point| {
+            f(c).map_err(|error| GridError::Cell {
+                line: point.y + 1,
+                column: point.x + 1,
+                error,
+            })
+        })
+    }
+
+    /// Like [`Grid::parse_with`], also finding the position of each `(marker, replacement)`
+    /// pair's marker, whose cell is parsed as the replacement. Each marker must appear once.
+    pub fn parse_with_markers<E, const N: usize>(
+        input: &str,
+        markers: [(char, char); N],
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<(Self, [Point<usize>; N]), GridError<E>> {
+        let mut found = [None; N];
+        let grid = Self::parse_cells(input, |c, point| {
+            let (line, column) = (point.y + 1, point.x + 1);
+            let c = match markers.iter().position(|&(marker, _)| marker == c) {
+                Some(i) if found[i].is_some() => {
+                    return Err(GridError::DuplicateMarker {
+                        marker: c,
+                        line,
+                        column,
+                    })
+                }
+                Some(i) => {
+                    found[i] = Some(point);
+                    markers[i].1
+                }
+                None => c,
+            };
+            f(c).map_err(|error| GridError::Cell {
+                line,
+                column,
+                error,
+            })
+        })?;
+
+        let mut positions = [Point::new(0, 0); N];
+        for ((position, found), (marker, _)) in positions.iter_mut().zip(found).zip(markers) {
+            *position = found.ok_or(GridError::MissingMarker(marker))?;
+        }
+        Ok((grid, positions))
+    }
+
+    fn parse_cells<E>(
+        input: &str,
+        mut f: impl FnMut(char, Point<usize>) -> Result<T, GridError<E>>,
+    ) -> Result<Self, GridError<E>> {
+        let input = input.trim_end_matches(['\r', '\n']);
+        let mut data = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut x = 0;
+            for c in line.chars() {
+                data.push(f(c, Point::new(x, y))?);
+                x += 1;
+            }
+            if y == 0 {
+                width = x;
+            } else if x != width {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected: width,
+                    found: x,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point<usize>) -> T) -> Self {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(Point::new(x, y)));
+            }
+        }
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
     #[inline]
     pub fn width(&self) -> usize {
         self.width
@@ -151,6 +329,64 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Moves `point` by `offset`, or `None` when that would leave the grid.
+    #[inline]
+    pub fn checked_offset(&self, point: Point<usize>, offset: Point<i64>) -> Option<Point<usize>> {
+        let moved = point.checked_offset(offset)?;
+        (moved.x < self.width && moved.y < self.height).then_some(moved)
+    }
+
+    /// The cell at `point` when the grid is repeated infinitely in every direction.
+    #[inline]
+    pub fn get_wrapped(&self, point: Point<i64>) -> &T {
+        let x = point.x.rem_euclid(self.width as i64) as usize;
+        let y = point.y.rem_euclid(self.height as i64) as usize;
+        &self.data[y * self.width + x]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|y| &self[y])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Borrows the `width` by `height` rectangle with its top left corner at `origin`.
+    pub fn view(&self, origin: Point<usize>, width: usize, height: usize) -> GridView<'_, T> {
+        assert!(
+            origin.x + width <= self.width && origin.y + height <= self.height,
+            "view is out of bounds"
+        );
+        GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// The in-bounds orthogonal neighbours of `point`, clockwise from north.
+    pub fn neighbors4(&self, point: Point<usize>) -> impl Iterator<Item = (Point<usize>, &T)> {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let neighbor = point.checked_offset(direction.offset())?;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `point`, clockwise from north.
+    pub fn neighbors8(&self, point: Point<usize>) -> impl Iterator<Item = (Point<usize>, &T)> {
+        Dir8::ALL.into_iter().filter_map(move |direction| {
+            let neighbor = point.checked_offset(direction.offset())?;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
     #[inline]
     pub fn iter(&self) -> GridIter<'_, T> {
         GridIter {
@@ -267,3 +503,229 @@ impl<'a, T> Iterator for GridIter<'a, T> {
         }
     }
 }
+
+/// A rectangular part of a [`Grid`], indexed from its own top left corner.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point<usize>) -> Option<&'a T> {
+        if point.x >= self.width || point.y >= self.height {
+            None
+        } else {
+            self.grid.get(self.origin + point)
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (grid, origin, width) = (self.grid, self.origin, self.width);
+        (origin.y..origin.y + self.height).map(move |y| &grid[y][origin.x..origin.x + width])
+    }
+
+    /// Each cell with its position in the view, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a T, Point<usize>)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, value)| (value, Point::new(x, y)))
+        })
+    }
+}
+
+impl<T> GridView<'_, T>
+where
+    T: Clone,
+{
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |p| self[p].clone())
+    }
+}
+
+impl<T, P> std::ops::Index<P> for GridView<'_, T>
+where
+    P: Into<Point<usize>>,
+{
+    type Output = T;
+
+    fn index(&self, point: P) -> &Self::Output {
+        self.get(point.into()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::parse("abc\ndef\nghi\n");
+        let around = |point: Point<usize>, n8: bool| -> String {
+            if n8 {
+                grid.neighbors8(point).map(|(_, &c)| c).collect()
+            } else {
+                grid.neighbors4(point).map(|(_, &c)| c).collect()
+            }
+        };
+
+        assert_eq!(around(Point::new(1, 1), false), "bfhd");
+        assert_eq!(around(Point::new(1, 1), true), "bcfihgda");
+        assert_eq!(around(Point::new(0, 0), false), "bd");
+        assert_eq!(around(Point::new(2, 2), true), "fhe");
+        assert_eq!(
+            grid.neighbors4(Point::new(2, 0)).collect::<Vec<_>>(),
+            [(Point::new(2, 1), &'f'), (Point::new(1, 0), &'b')]
+        );
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = Grid::parse("abc\ndef\n");
+        let text = |grid: &Grid<char>| {
+            grid.rows()
+                .map(|row| row.iter().collect::<String>())
+                .join("/")
+        };
+
+        assert_eq!(text(&grid.transpose()), "ad/be/cf");
+        assert_eq!(text(&grid.rotate_90()), "da/eb/fc");
+        assert_eq!(text(&grid.rotate_180()), "fed/cba");
+        assert_eq!(text(&grid.rotate_270()), "cf/be/ad");
+        assert_eq!(text(&grid.flip_horizontal()), "cba/fed");
+        assert_eq!(text(&grid.flip_vertical()), "def/abc");
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+        assert_eq!(grid.rotate_90().rotate_90(), grid.rotate_180());
+        assert_eq!(grid.transpose().transpose(), grid);
+
+        assert_eq!(text(&grid.tile(2, 2)), "abcabc/defdef/abcabc/defdef");
+        assert_eq!(grid.get_wrapped(Point::new(-1, -1)), &'f');
+        assert_eq!(grid.get_wrapped(Point::new(4, 5)), &'e');
+    }
+
+    #[test]
+    fn rows_columns_and_views() {
+        let grid = Grid::parse("abcd\nefgh\nijkl\n");
+        assert_eq!(grid.rows().nth(1), Some(&['e', 'f', 'g', 'h'][..]));
+        assert_eq!(grid.column(2).collect::<String>(), "cgk");
+        assert_eq!(
+            grid.columns().map(|c| c.collect::<String>()).join("/"),
+            "aei/bfj/cgk/dhl"
+        );
+
+        let view = grid.view(Point::new(1, 1), 2, 2);
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(view[(0, 0)], 'f');
+        assert_eq!(view.get(Point::new(2, 0)), None);
+        assert_eq!(
+            view.rows().collect::<Vec<_>>(),
+            [&['f', 'g'][..], &['j', 'k'][..]]
+        );
+        assert_eq!(
+            view.iter().map(|(&c, p)| (c, p.x, p.y)).last(),
+            Some(('k', 1, 1))
+        );
+        assert_eq!(view.to_grid(), Grid::parse("fg\njk\n"));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    fn tile(c: char) -> Result<Tile, String> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            c => Err(format!("unexpected {c:?}")),
+        }
+    }
+
+    #[test]
+    fn parse_typed_cells() {
+        let grid = Grid::parse_with("#.\r\n.#", tile).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid, Grid::parse_with("#.\n.#\n\n", tile).unwrap());
+
+        assert_eq!(
+            Grid::parse_with("..\n.x\n", tile),
+            Err(GridError::Cell {
+                line: 2,
+                column: 2,
+                error: "unexpected 'x'".to_owned()
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("...\n..\n", tile),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(Grid::parse_with("", tile).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn parse_markers() {
+        let (grid, [start, end]) =
+            Grid::parse_with_markers("S.#\n#.E\n", [('S', '.'), ('E', '.')], tile).unwrap();
+        assert_eq!((start, end), (Point::new(0, 0), Point::new(2, 1)));
+        assert_eq!(grid[start], Tile::Open);
+        assert_eq!(grid[end], Tile::Open);
+
+        assert_eq!(
+            Grid::parse_with_markers("S.\n.S\n", [('S', '.')], tile).unwrap_err(),
+            GridError::DuplicateMarker {
+                marker: 'S',
+                line: 2,
+                column: 2
+            }
+        );
+        assert_eq!(
+            Grid::parse_with_markers("S.\n..\n", [('S', '.'), ('E', '.')], tile).unwrap_err(),
+            GridError::MissingMarker('E')
+        );
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+        assert_eq!(Grid::parse_bytes("ab\ncd").height(), 2);
+        assert_eq!(
+            Grid::parse_bytes("ab\r\ncd\r\n"),
+            Grid::parse_bytes("ab\ncd\n")
+        );
+        assert_eq!(Grid::parse(" ab \n cd\n").rows().count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2 has 1 cells but the first line has 2")]
+    fn parse_ragged() {
+        Grid::parse_bytes("ab\nc\n");
+    }
+}
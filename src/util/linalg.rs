@@ -0,0 +1,440 @@
+use num::{BigInt, BigRational, FromPrimitive, Num, ToPrimitive};
+
+use super::math::extended_gcd;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LinalgError {
+    #[error("expected a square matrix but it is {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+    #[error("expected {expected} values but found {found}")]
+    DimensionMismatch { expected: usize, found: usize },
+    #[error("the matrix is singular")]
+    Singular,
+    #[error("the system has no solution")]
+    Inconsistent,
+    #[error("the system has infinitely many solutions")]
+    Underdetermined,
+    #[error("the solution isn't in integers")]
+    NotInteger,
+}
+
+/// A dense matrix of exact numbers, such as [`num::Rational64`] or [`BigRational`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Num,
+{
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows * cols).map(|i| f(i / cols, i % cols)).collect();
+        Matrix { rows, cols, data }
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self::from_fn(rows, cols, |_, _| T::zero())
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |r, c| if r == c { T::one() } else { T::zero() })
+    }
+
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "matrix rows have different lengths"
+        );
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |r, c| self[(c, r)].clone())
+    }
+
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, LinalgError> {
+        self.check_len(v.len(), self.cols)?;
+        Ok((0..self.rows)
+            .map(|r| {
+                self.row(r)
+                    .iter()
+                    .zip(v)
+                    .fold(T::zero(), |sum, (a, b)| sum + a.clone() * b.clone())
+            })
+            .collect())
+    }
+
+    pub fn mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        self.check_len(other.rows, self.cols)?;
+        Ok(Self::from_fn(self.rows, other.cols, |r, c| {
+            (0..self.cols).fold(T::zero(), |sum, k| {
+                sum + self[(r, k)].clone() * other[(k, c)].clone()
+            })
+        }))
+    }
+
+    /// The reduced row echelon form, by Gauss-Jordan elimination.
+    pub fn rref(&self) -> Self {
+        let mut reduced = self.clone();
+        reduced.reduce();
+        reduced
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduce().0.len()
+    }
+
+    pub fn determinant(&self) -> Result<T, LinalgError> {
+        self.check_square()?;
+        let (pivots, det) = self.clone().reduce();
+        Ok(if pivots.len() < self.rows {
+            T::zero()
+        } else {
+            det
+        })
+    }
+
+    pub fn inverse(&self) -> Result<Self, LinalgError> {
+        self.check_square()?;
+        let n = self.rows;
+        let identity = Self::identity(n);
+        let mut augmented = Self::from_fn(n, 2 * n, |r, c| {
+            if c < n {
+                self[(r, c)].clone()
+            } else {
+                identity[(r, c - n)].clone()
+            }
+        });
+        let (pivots, _) = augmented.reduce();
+        if pivots.len() < n || pivots.last().is_some_and(|&pivot| pivot >= n) {
+            return Err(LinalgError::Singular);
+        }
+        Ok(Self::from_fn(n, n, |r, c| augmented[(r, c + n)].clone()))
+    }
+
+    /// The unique `x` where `self * x == b`.
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, LinalgError> {
+        self.check_len(b.len(), self.rows)?;
+        let mut augmented = Self::from_fn(self.rows, self.cols + 1, |r, c| {
+            if c < self.cols {
+                self[(r, c)].clone()
+            } else {
+                b[r].clone()
+            }
+        });
+        let (pivots, _) = augmented.reduce();
+        if pivots.last() == Some(&self.cols) {
+            return Err(LinalgError::Inconsistent);
+        }
+        if pivots.len() < self.cols {
+            return Err(LinalgError::Underdetermined);
+        }
+        Ok((0..self.cols)
+            .map(|r| augmented[(r, self.cols)].clone())
+            .collect())
+    }
+
+    /// Reduces in place to reduced row echelon form, returning the column of each pivot and
+    /// the determinant of the leading square when every column up to it has a pivot.
+    fn reduce(&mut self) -> (Vec<usize>, T) {
+        let mut pivots = vec![];
+        let mut det = T::one();
+        for col in 0..self.cols {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|&r| !self[(r, col)].is_zero()) else {
+                det = T::zero();
+                continue;
+            };
+            if pivot != row {
+                self.swap_rows(pivot, row);
+                det = T::zero() - det;
+            }
+
+            let value = self[(row, col)].clone();
+            det = det * value.clone();
+            for c in col..self.cols {
+                self[(row, c)] = self[(row, c)].clone() / value.clone();
+            }
+            for r in 0..self.rows {
+                let factor = self[(r, col)].clone();
+                if r == row || factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    let v = self[(row, c)].clone() * factor.clone();
+                    self[(r, c)] = self[(r, c)].clone() - v;
+                }
+            }
+            pivots.push(col);
+        }
+        (pivots, det)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.cols {
+            self.data.swap(a * self.cols + c, b * self.cols + c);
+        }
+    }
+
+    fn check_square(&self) -> Result<(), LinalgError> {
+        if self.rows == self.cols {
+            Ok(())
+        } else {
+            Err(LinalgError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            })
+        }
+    }
+
+    fn check_len(&self, found: usize, expected: usize) -> Result<(), LinalgError> {
+        if found == expected {
+            Ok(())
+        } else {
+            Err(LinalgError::DimensionMismatch { expected, found })
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Num + FromPrimitive,
+{
+    pub fn from_ints(rows: impl IntoIterator<Item = impl IntoIterator<Item = i64>>) -> Self {
+        Self::from_rows(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(|v| T::from_i64(v).unwrap())),
+        )
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+/// The unique integer `x` where `a * x == b`, solved over big rationals so large right hand
+/// sides can't overflow.
+pub fn solve_integer<const R: usize, const C: usize>(
+    a: [[i64; C]; R],
+    b: [i64; R],
+) -> Result<[i64; C], LinalgError> {
+    let matrix = Matrix::<BigRational>::from_ints(a);
+    let b = b.map(|v| BigRational::from_integer(BigInt::from(v)));
+    let x = matrix.solve(&b)?;
+    let x = x
+        .iter()
+        .map(|v| {
+            v.is_integer()
+                .then(|| v.to_integer().to_i64())
+                .flatten()
+                .ok_or(LinalgError::NotInteger)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(x.try_into().unwrap())
+}
+
+/// Every integer solution of `a * x + b * y == c`, which are `x + k * dx` and `y + k * dy`
+/// for any integer `k`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diophantine {
+    /// The smallest non-negative `x`.
+    pub x: i64,
+    pub y: i64,
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl Diophantine {
+    pub fn at(&self, k: i64) -> (i64, i64) {
+        (self.x + k * self.dx, self.y + k * self.dy)
+    }
+}
+
+/// Solves `a * x + b * y == c` in integers, `None` when there are no solutions, `a` and `b`
+/// are both zero or the solution doesn't fit in an `i64`.
+pub fn solve_diophantine(a: i64, b: i64, c: i64) -> Option<Diophantine> {
+    if a == 0 && b == 0 {
+        return None;
+    }
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let (g, x, y) = extended_gcd(a, b);
+    if c % g != 0 {
+        return None;
+    }
+    let (x, y) = (x * (c / g), y * (c / g));
+    let (dx, dy) = (b / g, -a / g);
+    // Shift to the smallest non-negative x, or leave x alone when it can't change
+    let k = if dx == 0 {
+        0
+    } else {
+        -x.div_euclid(dx.abs()) * dx.signum()
+    };
+    Some(Diophantine {
+        x: (x + k * dx).try_into().ok()?,
+        y: (y + k * dy).try_into().ok()?,
+        dx: dx.try_into().ok()?,
+        dy: dy.try_into().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Rational64;
+
+    use super::*;
+
+    fn ratio(n: i64, d: i64) -> Rational64 {
+        Rational64::new(n, d)
+    }
+
+    #[test]
+    fn eliminate() {
+        let m = Matrix::<Rational64>::from_ints([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+        let b = [8, -11, -3].map(Rational64::from_integer);
+        assert_eq!(
+            m.solve(&b),
+            Ok(vec![ratio(2, 1), ratio(3, 1), ratio(-1, 1)])
+        );
+        assert_eq!(m.determinant(), Ok(ratio(-1, 1)));
+        assert_eq!(m.rank(), 3);
+        assert_eq!(m.rref(), Matrix::identity(3));
+
+        let inverse = m.inverse().unwrap();
+        assert_eq!(m.mul(&inverse), Ok(Matrix::identity(3)));
+        assert_eq!(inverse.mul_vec(&b), m.solve(&b));
+
+        let half = Matrix::<Rational64>::from_ints([[2, 0], [0, 4]]);
+        assert_eq!(
+            half.inverse().unwrap(),
+            Matrix::from_rows([[ratio(1, 2), ratio(0, 1)], [ratio(0, 1), ratio(1, 4)]])
+        );
+        assert_eq!(half.transpose(), half);
+    }
+
+    #[test]
+    fn degenerate_systems() {
+        let singular = Matrix::<Rational64>::from_ints([[1, 2], [2, 4]]);
+        assert_eq!(singular.determinant(), Ok(ratio(0, 1)));
+        assert_eq!(singular.rank(), 1);
+        assert_eq!(singular.inverse(), Err(LinalgError::Singular));
+        let b = [3, 6].map(Rational64::from_integer);
+        assert_eq!(singular.solve(&b), Err(LinalgError::Underdetermined));
+        let b = [3, 7].map(Rational64::from_integer);
+        assert_eq!(singular.solve(&b), Err(LinalgError::Inconsistent));
+
+        let empty = Matrix::<Rational64>::from_rows(Vec::<Vec<_>>::new());
+        assert_eq!(empty.inverse(), Ok(empty.clone()));
+
+        let wide = Matrix::<Rational64>::from_ints([[1, 2, 3]]);
+        assert_eq!(
+            wide.determinant(),
+            Err(LinalgError::NotSquare { rows: 1, cols: 3 })
+        );
+        assert_eq!(
+            wide.solve(&[]),
+            Err(LinalgError::DimensionMismatch {
+                expected: 1,
+                found: 0
+            })
+        );
+
+        // Needs a row swap, which flips the sign
+        let swapped = Matrix::<Rational64>::from_ints([[0, 1], [1, 0]]);
+        assert_eq!(swapped.determinant(), Ok(ratio(-1, 1)));
+
+        // Overdetermined but consistent
+        let tall = Matrix::<Rational64>::from_ints([[1, 0], [0, 1], [1, 1]]);
+        let b = [1, 2, 3].map(Rational64::from_integer);
+        assert_eq!(tall.solve(&b), Ok(vec![ratio(1, 1), ratio(2, 1)]));
+    }
+
+    #[test]
+    fn integer_solutions() {
+        // Claw machines from 2024 day 13, pressing A and B to reach the prize
+        assert_eq!(
+            solve_integer([[94, 22], [34, 67]], [8400, 5400]),
+            Ok([80, 40])
+        );
+        assert_eq!(
+            solve_integer([[26, 67], [66, 21]], [12748, 12176]),
+            Err(LinalgError::NotInteger)
+        );
+        let far = 10_000_000_000_000;
+        assert_eq!(
+            solve_integer([[26, 67], [66, 21]], [12748 + far, 12176 + far]),
+            Ok([118679050709, 103199174542])
+        );
+    }
+
+    #[test]
+    fn diophantine() {
+        let solution = solve_diophantine(6, 9, 21).unwrap();
+        assert_eq!((solution.x, solution.dx, solution.dy), (2, 3, -2));
+        for k in -3..3 {
+            let (x, y) = solution.at(k);
+            assert_eq!(6 * x + 9 * y, 21);
+        }
+
+        let solution = solve_diophantine(-4, 10, 6).unwrap();
+        assert!(solution.x >= 0 && solution.x < 5);
+        assert_eq!(-4 * solution.x + 10 * solution.y, 6);
+
+        assert_eq!(solve_diophantine(6, 9, 20), None);
+        assert_eq!(solve_diophantine(0, 0, 0), None);
+        assert_eq!(
+            solve_diophantine(0, 5, 10),
+            Some(Diophantine {
+                x: 0,
+                y: 2,
+                dx: 1,
+                dy: 0
+            })
+        );
+
+        // The step for y would be 2^63
+        assert_eq!(solve_diophantine(i64::MIN, 1, 0), None);
+        let solution = solve_diophantine(i64::MAX, 1, i64::MAX).unwrap();
+        assert_eq!((solution.x, solution.y), (0, i64::MAX));
+        assert_eq!((solution.dx, solution.dy), (1, -i64::MAX));
+    }
+}
@@ -1,21 +1,415 @@
-use num::BigUint;
+use num::{BigInt, BigRational, Integer, Signed, Zero};
 
-pub fn lcm<N, I>(numbers: I) -> BigUint
+/// The least common multiple of every number, one when there are none.
+pub fn lcm<T, I>(numbers: I) -> T
 where
-    N: Into<BigUint>,
-    I: Iterator<Item = N>,
+    T: Integer,
+    I: IntoIterator<Item = T>,
 {
-    numbers.fold(BigUint::from(1_usize), |acc, f| {
-        num::integer::lcm(acc, f.into())
-    })
+    numbers
+        .into_iter()
+        .fold(T::one(), |acc, n| num::integer::lcm(acc, n))
 }
 
-pub fn gcd<N, I>(numbers: I) -> BigUint
+/// The greatest common divisor of every number, zero when there are none.
+pub fn gcd<T, I>(numbers: I) -> T
 where
-    N: Into<BigUint>,
-    I: Iterator<Item = N>,
+    T: Integer,
+    I: IntoIterator<Item = T>,
 {
-    numbers.fold(BigUint::from(0_usize), |acc, f| {
-        num::integer::gcd(acc, f.into())
-    })
+    numbers
+        .into_iter()
+        .fold(T::zero(), |acc, n| num::integer::gcd(acc, n))
+}
+
+/// `(g, x, y)` where `g` is the non-negative gcd of `a` and `b`, and `a * x + b * y == g`.
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T, T)
+where
+    T: Integer + Signed + Copy,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::one(), T::zero());
+    let (mut old_t, mut t) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// The `x` in `0..modulus` where `a * x` is one modulo `modulus`, if `a` and `modulus` are
+/// coprime.
+pub fn mod_inverse<T>(a: T, modulus: T) -> Option<T>
+where
+    T: Integer + Signed + Copy,
+{
+    let (g, x, _) = extended_gcd(a, modulus);
+    g.is_one().then(|| x.mod_floor(&modulus))
+}
+
+/// `base` to the power of `exp` modulo `modulus`, without overflowing.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every pair, returning the smallest non-negative
+/// `x` and the modulus of all solutions, which is the lcm of the moduli.
+///
+/// The moduli don't need to be coprime. `None` means the congruences contradict each other, a
+/// modulus isn't positive or the lcm of the moduli doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for (residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        let (g, p, _) = extended_gcd(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // x + m * k ≡ residue (mod modulus), so k ≡ p * diff / g (mod modulus / g)
+        let step = modulus / g;
+        let k = (diff / g % step * (p % step)).mod_floor(&step);
+        // Both fit in an i64, so their product can't overflow
+        x += m * k;
+        m *= step;
+        i64::try_from(m).ok()?;
+        x = x.mod_floor(&m);
+    }
+    Some((x.try_into().ok()?, m.try_into().ok()?))
+}
+
+/// Every prime up to and including `limit`, by the sieve of Eratosthenes.
+pub fn primes_up_to(limit: usize) -> Vec<usize> {
+    let mut composite = vec![false; limit + 1];
+    let mut primes = vec![];
+    for n in 2..=limit {
+        if composite[n] {
+            continue;
+        }
+        primes.push(n);
+        for multiple in (n * n..=limit).step_by(n) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
+
+/// The prime factors of `n` with their exponents, smallest first.
+pub fn factorise(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p * p <= n {
+        let mut exp = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exp += 1;
+        }
+        if exp > 0 {
+            factors.push((p, exp));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Every divisor of `n` including one and itself, in ascending order.
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for (p, exp) in factorise(n) {
+        let len = divisors.len();
+        let mut power = 1;
+        for _ in 0..exp {
+            power *= p;
+            for i in 0..len {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// The sum of every divisor of `n`, from its prime factors.
+pub fn divisor_sum(n: u64) -> u64 {
+    factorise(n)
+        .into_iter()
+        .map(|(p, exp)| (p.pow(exp + 1) - 1) / (p - 1))
+        .product()
+}
+
+/// Repeated differences of `values`, starting with `values` itself and ending at the first
+/// row which is all zeros or has a single value.
+pub fn difference_table(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut table = vec![values.to_vec()];
+    loop {
+        let last = table.last().unwrap();
+        if last.len() <= 1 || last.iter().all(|v| *v == 0) {
+            return table;
+        }
+        let next = last.windows(2).map(|w| w[1] - w[0]).collect();
+        table.push(next);
+    }
+}
+
+/// The value at `x` of the lowest degree polynomial through `values` taken at `0, 1, 2...`,
+/// from Newton's forward difference formula. `x` can be far outside the samples, or negative
+/// to extrapolate backwards.
+pub fn newton_forward(values: &[i64], x: i64) -> BigInt {
+    let x = BigInt::from(x);
+    let mut result = BigInt::zero();
+    // x choose k, which is an integer for every integer x
+    let mut binomial = BigInt::from(1);
+    for (k, row) in difference_table(values).iter().enumerate() {
+        if let Some(&first) = row.first() {
+            result += &binomial * first;
+        }
+        binomial = binomial * (&x - k) / (k + 1);
+    }
+    result
+}
+
+/// A polynomial with exact coefficients, lowest power first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<BigRational>,
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<BigRational>) -> Self {
+        let mut polynomial = Polynomial { coefficients };
+        while polynomial.coefficients.last().is_some_and(Zero::is_zero) {
+            polynomial.coefficients.pop();
+        }
+        polynomial
+    }
+
+    /// The lowest degree polynomial through every point, by Lagrange interpolation.
+    ///
+    /// Panics if two points share an `x`.
+    pub fn interpolate(points: &[(i64, i64)]) -> Self {
+        let mut coefficients = vec![BigRational::zero(); points.len()];
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            // The basis polynomial which is one at xi and zero at every other x
+            let mut basis = vec![BigRational::from_integer(yi.into())];
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                assert_ne!(xi, xj, "points must have distinct x");
+                let scale = BigRational::new(1.into(), (xi - xj).into());
+                let mut next = vec![BigRational::zero(); basis.len() + 1];
+                for (k, c) in basis.iter().enumerate() {
+                    let c = c * &scale;
+                    next[k + 1] += &c;
+                    next[k] -= c * BigInt::from(xj);
+                }
+                basis = next;
+            }
+            for (k, c) in basis.into_iter().enumerate() {
+                coefficients[k] += c;
+            }
+        }
+        Self::new(coefficients)
+    }
+
+    /// Fits the polynomial generating `values` at `0, 1, 2...`, with its degree found from
+    /// the differences. `None` when the differences never become constant, so there aren't
+    /// enough values to be sure of the degree.
+    pub fn fit(values: &[i64]) -> Option<Self> {
+        let table = difference_table(values);
+        if !table.last().unwrap().iter().all(|v| *v == 0) {
+            return None;
+        }
+        // The constant row before the zeros is at the index of the degree
+        let degree = table.len().saturating_sub(2);
+        let points = values
+            .iter()
+            .take(degree + 1)
+            .enumerate()
+            .map(|(x, &y)| (x as i64, y))
+            .collect::<Vec<_>>();
+        Some(Self::interpolate(&points))
+    }
+
+    /// The degree, with zero for constant polynomials including zero itself.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    pub fn eval(&self, x: i64) -> BigRational {
+        let x = BigRational::from_integer(x.into());
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |acc, c| acc * &x + c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        // Ghost cycle lengths from 2023 day 8 share the direction count as a factor
+        assert_eq!(lcm([2usize, 3]), 6);
+        assert_eq!(lcm([263u64 * 43, 263 * 47, 263 * 59]), 263 * 43 * 47 * 59);
+        assert_eq!(gcd([12i32, -18, 30]), 6);
+        assert_eq!(gcd(Vec::<u8>::new()), 0);
+        assert_eq!(
+            lcm([BigUint::from(4u8), BigUint::from(6u8)]),
+            BigUint::from(12u8)
+        );
+    }
+
+    #[test]
+    fn extended_euclid() {
+        for (a, b) in [(240i64, 46), (-7, 3), (0, 5), (17, -51)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd([a, b]));
+            assert_eq!(a * x + b * y, g);
+        }
+
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6i64, 9), None);
+    }
+
+    #[test]
+    fn modular_exponentiation() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        // Public key handshake from 2020 day 25
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(17807724, 8, 20201227), 14897079);
+        assert_eq!(mod_pow(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        // Bus departures from 2020 day 13: 7,13,x,x,59,x,31,19
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences = buses.map(|(bus, offset)| (-offset, bus));
+        assert_eq!(crt(congruences), Some((1068781, 7 * 13 * 59 * 31 * 19)));
+
+        assert_eq!(crt([(2, 6), (8, 9)]), Some((8, 18)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(crt([(1, -5)]), None);
+        // The lcm of these primes is beyond i64::MAX
+        let large = [(1, 1_000_000_007), (2, 998_244_353), (3, 1_000_000_009)];
+        assert_eq!(crt(large), None);
+        assert_eq!(crt([(1, i64::MAX), (1, i64::MAX)]), Some((1, i64::MAX)));
+    }
+
+    #[test]
+    fn primes_and_divisors() {
+        assert_eq!(primes_up_to(30), [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(primes_up_to(1).is_empty());
+        assert_eq!(primes_up_to(100_000).len(), 9592);
+
+        assert_eq!(factorise(360), [(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorise(1), []);
+        assert_eq!(
+            factorise(600851475143),
+            [(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+
+        assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(1), [1]);
+        // Presents delivered to the first houses in 2015 day 20, divided by ten
+        let sums = (1..=9).map(divisor_sum).collect::<Vec<_>>();
+        assert_eq!(sums, [1, 3, 4, 7, 6, 12, 8, 15, 13]);
+        for n in 1..200 {
+            assert_eq!(divisor_sum(n), divisors(n).iter().sum::<u64>());
+        }
+    }
+
+    fn rational(n: i64, d: i64) -> BigRational {
+        BigRational::new(n.into(), d.into())
+    }
+
+    #[test]
+    fn forward_differences() {
+        // Histories from 2023 day 9, extrapolated both ways
+        let history = [10, 13, 16, 21, 30, 45];
+        assert_eq!(
+            difference_table(&history),
+            [
+                vec![10, 13, 16, 21, 30, 45],
+                vec![3, 3, 5, 9, 15],
+                vec![0, 2, 4, 6],
+                vec![2, 2, 2],
+                vec![0, 0],
+            ]
+        );
+        assert_eq!(newton_forward(&history, 6), BigInt::from(68));
+        assert_eq!(newton_forward(&history, -1), BigInt::from(5));
+        assert_eq!(newton_forward(&[0, 3, 6, 9, 12, 15], 6), BigInt::from(18));
+        assert_eq!(newton_forward(&[], 6), BigInt::zero());
+    }
+
+    #[test]
+    fn interpolate_and_fit() {
+        let p = Polynomial::interpolate(&[(1, 1), (2, 4), (-1, 1)]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(
+            p.coefficients(),
+            [rational(0, 1), rational(0, 1), rational(1, 1)]
+        );
+
+        let p = Polynomial::interpolate(&[(0, 0), (2, 1)]);
+        assert_eq!(p.eval(3), rational(3, 2));
+
+        let line = Polynomial::fit(&[3, 5, 7, 9]).unwrap();
+        assert_eq!(line.coefficients(), [rational(3, 1), rational(2, 1)]);
+        assert_eq!(Polynomial::fit(&[1, 2, 4, 8]), None);
+        assert_eq!(Polynomial::fit(&[0, 0]).unwrap().degree(), 0);
+        assert!(Polynomial::fit(&[0, 0]).unwrap().coefficients().is_empty());
+    }
+
+    #[test]
+    fn quadratic_growth() {
+        // Reachable plots after 65 + 131 * n steps grow quadratically in n, so the first few
+        // samples predict step 26501365
+        let f = |n: i64| 3648 + 14560 * n + 14571 * n * n;
+        let samples = [f(0), f(1), f(2), f(3)];
+        let p = Polynomial::fit(&samples).unwrap();
+        assert_eq!(p.degree(), 2);
+
+        let n = (26501365 - 65) / 131;
+        let expected = BigInt::from(3648) + BigInt::from(14560) * n + BigInt::from(14571) * n * n;
+        assert_eq!(p.eval(n), BigRational::from_integer(expected.clone()));
+        assert_eq!(newton_forward(&samples, n), expected);
+        assert_eq!(newton_forward(&samples[..3], n), expected);
+    }
 }
@@ -1,49 +1,30 @@
+pub mod answers;
 pub mod fast;
 pub mod grid;
+pub mod linalg;
 pub mod math;
+pub mod ocr;
 pub mod point;
-
-const DATA_PATH: &str = "data/";
-
-async fn cookie() -> String {
-    let env_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(".env");
-    let env = tokio::fs::read_to_string(env_path).await.unwrap();
-    let (_, cookie) = env
-        .lines()
-        .find(|line| line.starts_with("AOC_COOKIE"))
-        .unwrap()
-        .split_once('=')
-        .unwrap();
-    cookie.to_string()
+pub mod point_n;
+pub mod puzzle;
+pub mod render;
+pub mod search;
+pub mod sparse_grid;
+pub mod store;
+pub mod submit;
+
+pub use answers::check_answer;
+use store::{default_store, validate, InputError, InputStore};
+
+pub async fn try_input(year: u32, day: u32) -> Result<String, InputError> {
+    validate(year, day)?;
+    default_store().get(year, day).await
 }
 
 pub async fn input(year: u32, day: u32) -> String {
-    assert!(year >= 2015);
-    assert!((1..=25).contains(&day));
-
-    let file_path = format!("{DATA_PATH}/{year}/day{day}.txt");
-    match tokio::fs::read_to_string(&file_path).await {
+    match try_input(year, day).await {
         Ok(input) => input,
-        Err(_) => {
-            let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-            let client = reqwest::Client::new();
-            let res = client
-                .get(&url)
-                .header("Cookie", cookie().await)
-                .header(
-                    "User-Agent",
-                    "github.com/grant0417/advent by grant@gurvis.net",
-                )
-                .send()
-                .await
-                .unwrap();
-            let text = res.error_for_status().unwrap().text().await.unwrap();
-            tokio::fs::create_dir_all(format!("{DATA_PATH}/{year}"))
-                .await
-                .unwrap();
-            tokio::fs::write(&file_path, &text).await.unwrap();
-            text
-        }
+        Err(e) => panic!("failed to load input for {year} day {day}: {e}"),
     }
 }
 
@@ -0,0 +1,320 @@
+use std::fmt;
+
+use super::grid::Grid;
+use super::point::Point;
+
+/// The letters drawn 6 rows tall, trimmed of blank columns.
+const FONT_6: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The letters drawn 10 rows tall, trimmed of blank columns.
+const FONT_10: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// A glyph which isn't a letter in the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// The position of the glyph in the text.
+    pub index: usize,
+    /// The first column of the glyph in the picture.
+    pub column: usize,
+    /// The glyph drawn with `#` and `.`.
+    pub picture: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum OcrError {
+    #[error("no letters found")]
+    Empty,
+    #[error("letters are {0} rows tall, expected 6 or 10")]
+    UnknownHeight(usize),
+    #[error("read {text:?} with unrecognised glyphs:{}", DisplayGlyphs(.unknown))]
+    Unrecognised {
+        /// The text read, with `?` for each unrecognised glyph.
+        text: String,
+        unknown: Vec<UnknownGlyph>,
+    },
+}
+
+struct DisplayGlyphs<'a>(&'a [UnknownGlyph]);
+
+impl fmt::Display for DisplayGlyphs<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for glyph in self.0 {
+            write!(
+                f,
+                "\nglyph {} at column {}:\n{}",
+                glyph.index, glyph.column, glyph.picture
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the block letters drawn by the lit cells of `grid`.
+///
+/// Letters are separated by blank columns and the font is chosen by their height.
+pub fn recognise(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let lit_rows = grid
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.contains(&true))
+        .map(|(y, _)| y)
+        .collect::<Vec<_>>();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Empty);
+    };
+    let font = match bottom - top + 1 {
+        6 => FONT_6,
+        10 => FONT_10,
+        height => return Err(OcrError::UnknownHeight(height)),
+    };
+
+    let lit_column = |x: usize| (top..=bottom).any(|y| grid[(x, y)]);
+    let mut text = String::new();
+    let mut unknown = vec![];
+    let mut x = 0;
+    while x < grid.width() {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.width() && lit_column(x) {
+            x += 1;
+        }
+        let picture = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if grid[(x, y)] { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match font.iter().find(|(_, glyph)| *glyph == picture) {
+            Some(&(c, _)) => text.push(c),
+            None => {
+                unknown.push(UnknownGlyph {
+                    index: text.chars().count(),
+                    column: start,
+                    picture,
+                });
+                text.push('?');
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unrecognised { text, unknown })
+    }
+}
+
+/// Reads the block letters drawn by a set of lit points.
+pub fn recognise_points(points: impl IntoIterator<Item = Point<i64>>) -> Result<String, OcrError> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let Some(min_x) = points.iter().map(|p| p.x).min() else {
+        return Err(OcrError::Empty);
+    };
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_x = points.iter().map(|p| p.x).max().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
+
+    let mut grid = Grid::filled(
+        (max_x - min_x + 1) as usize,
+        (max_y - min_y + 1) as usize,
+        false,
+    );
+    for p in points {
+        grid[((p.x - min_x) as usize, (p.y - min_y) as usize)] = true;
+    }
+    recognise(&grid)
+}
+
+/// Reads the block letters in a rendered picture, where `#` or `█` are lit.
+///
+/// Lines may have different lengths, such as when trailing spaces have been trimmed.
+pub fn recognise_str(picture: &str) -> Result<String, OcrError> {
+    let lines = picture
+        .lines()
+        .map(|line| line.chars().map(|c| matches!(c, '#' | '█')).collect())
+        .collect::<Vec<Vec<_>>>();
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let grid = Grid::from_fn(width, lines.len(), |p| {
+        lines[p.y].get(p.x).copied().unwrap_or(false)
+    });
+    recognise(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn recognise_small_letters() {
+        let picture = indoc! {"
+            #### ####   ## #  # #### #    ###  #
+            #    #       # # #     # #    #  # #
+            ###  ###     # ##     #  #    ###  #
+            #    #       # # #   #   #    #  # #
+            #    #    #  # # #  #    #    #  # #
+            #### #     ##  #  # #### #### ###  ####
+        "};
+        assert_eq!(recognise_str(picture).unwrap(), "EFJKZLBL");
+
+        // Narrow and wide letters, with blank rows around them
+        let picture =
+            "\n###  #   #\n #   #   #\n #    # # \n #     #  \n #     #  \n###    #  \n\n";
+        assert_eq!(recognise_str(picture).unwrap(), "IY");
+    }
+
+    #[test]
+    fn recognise_large_letters() {
+        let picture = indoc! {"
+            #....#.....###...####.
+            #....#......#...#....#
+            #....#......#...#.....
+            #....#......#...#.....
+            ######......#...#.....
+            #....#......#...#..###
+            #....#......#...#....#
+            #....#..#...#...#....#
+            #....#..#...#...#...##
+            #....#...###.....###.#
+        "};
+        assert_eq!(recognise_str(picture).unwrap(), "HJG");
+    }
+
+    #[test]
+    fn recognise_point_sets() {
+        let h = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 2),
+            (2, 2),
+        ];
+        let points = h
+            .iter()
+            .flat_map(|&(x, y)| [(x, y), (3 - x, y)])
+            .map(|(x, y)| Point::new(x - 10, y + 5));
+        assert_eq!(recognise_points(points).unwrap(), "H");
+        assert_eq!(recognise_points([]), Err(OcrError::Empty));
+    }
+
+    #[test]
+    fn report_unknown_glyphs() {
+        assert_eq!(recognise_str("#\n#\n#\n"), Err(OcrError::UnknownHeight(3)));
+
+        let picture = indoc! {"
+            .##...##
+            #..#...#
+            #..#...#
+            ####...#
+            #..#...#
+            #..#...#
+        "};
+        let err = recognise_str(picture).unwrap_err();
+        assert_eq!(
+            err,
+            OcrError::Unrecognised {
+                text: "A?".to_string(),
+                unknown: vec![UnknownGlyph {
+                    index: 1,
+                    column: 6,
+                    picture: "##\n.#\n.#\n.#\n.#\n.#".to_string(),
+                }],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "read \"A?\" with unrecognised glyphs:\nglyph 1 at column 6:\n##\n.#\n.#\n.#\n.#\n.#"
+        );
+    }
+}
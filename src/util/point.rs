@@ -104,9 +104,70 @@ macro_rules! impl_ops {
                 }
             }
 
+            /// Panics if the distance doesn't fit in the coordinate type, such as from
+            /// `(-128, -128)` to `(127, 127)` as `i8`.
             pub fn manhattan_distance(&self, other: &Self) -> $t {
-                (self.x as i64 - other.x as i64).unsigned_abs() as $t
-                    + (self.y as i64 - other.y as i64).unsigned_abs() as $t
+                self.checked_manhattan_distance(other)
+                    .expect("manhattan distance overflowed")
+            }
+
+            /// The manhattan distance, or `None` if it doesn't fit in the coordinate type.
+            pub fn checked_manhattan_distance(&self, other: &Self) -> Option<$t> {
+                let distance = self
+                    .x
+                    .abs_diff(other.x)
+                    .checked_add(self.y.abs_diff(other.y))?;
+                <$t>::try_from(distance).ok()
+            }
+
+            /// The larger of the differences along each axis, panics if it doesn't fit in the
+            /// coordinate type.
+            pub fn chebyshev_distance(&self, other: &Self) -> $t {
+                let distance = self.x.abs_diff(other.x).max(self.y.abs_diff(other.y));
+                <$t>::try_from(distance).expect("chebyshev distance overflowed")
+            }
+        }
+
+        impl std::ops::Mul<$t> for Point<$t> {
+            type Output = Self;
+
+            fn mul(self, v: $t) -> Self::Output {
+                self.scale(v)
+            }
+        }
+
+        /// Component-wise.
+        impl std::ops::Mul for Point<$t> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self::Output {
+                Self {
+                    x: self.x * other.x,
+                    y: self.y * other.y,
+                }
+            }
+        }
+
+        impl std::ops::Div<$t> for Point<$t> {
+            type Output = Self;
+
+            fn div(self, v: $t) -> Self::Output {
+                Self {
+                    x: self.x / v,
+                    y: self.y / v,
+                }
+            }
+        }
+
+        /// Component-wise.
+        impl std::ops::Div for Point<$t> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self::Output {
+                Self {
+                    x: self.x / other.x,
+                    y: self.y / other.y,
+                }
             }
         }
 
@@ -157,6 +218,55 @@ impl_ops!(i32);
 impl_ops!(i64);
 impl_ops!(isize);
 
+macro_rules! impl_signed_ops {
+    ($t: ty) => {
+        impl Point<$t> {
+            /// Rotates a quarter turn clockwise about the origin, with `y` increasing to the
+            /// south.
+            pub fn rotate_cw(&self) -> Self {
+                Self {
+                    x: -self.y,
+                    y: self.x,
+                }
+            }
+
+            /// Rotates a quarter turn counterclockwise about the origin, with `y` increasing
+            /// to the south.
+            pub fn rotate_ccw(&self) -> Self {
+                Self {
+                    x: self.y,
+                    y: -self.x,
+                }
+            }
+
+            /// The sign of each component, such as to step one cell at a time towards a point.
+            pub fn signum(&self) -> Self {
+                Self {
+                    x: self.x.signum(),
+                    y: self.y.signum(),
+                }
+            }
+        }
+
+        impl std::ops::Neg for Point<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    x: -self.x,
+                    y: -self.y,
+                }
+            }
+        }
+    };
+}
+
+impl_signed_ops!(i8);
+impl_signed_ops!(i16);
+impl_signed_ops!(i32);
+impl_signed_ops!(i64);
+impl_signed_ops!(isize);
+
 impl TryInto<Point<usize>> for Point<i64> {
     type Error = TryFromIntError;
 
@@ -200,3 +310,229 @@ impl TryInto<Point<i64>> for (usize, usize) {
         })
     }
 }
+
+impl Point<usize> {
+    /// Moves by `offset`, or `None` when that would leave the non-negative quadrant.
+    pub fn checked_offset(self, offset: Point<i64>) -> Option<Self> {
+        self.checked_add_signed(Point::new(offset.x as isize, offset.y as isize))
+    }
+
+    /// Like [`usize::checked_add_signed`] for each component.
+    pub fn checked_add_signed(self, delta: Point<isize>) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(delta.x)?,
+            y: self.y.checked_add_signed(delta.y)?,
+        })
+    }
+}
+
+/// A cardinal direction, with `y` increasing to the south as it does in a [`Grid`].
+///
+/// [`Grid`]: super::grid::Grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub const fn offset(self) -> Point<i64> {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+/// A cardinal or diagonal direction, with `y` increasing to the south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Turns 45 degrees anticlockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub const fn offset(self) -> Point<i64> {
+        match self {
+            Dir8::North => Point::new(0, -1),
+            Dir8::NorthEast => Point::new(1, -1),
+            Dir8::East => Point::new(1, 0),
+            Dir8::SouthEast => Point::new(1, 1),
+            Dir8::South => Point::new(0, 1),
+            Dir8::SouthWest => Point::new(-1, 1),
+            Dir8::West => Point::new(-1, 0),
+            Dir8::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Dir8::North,
+            Direction::East => Dir8::East,
+            Direction::South => Dir8::South,
+            Direction::West => Dir8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.reverse().offset(),
+                Point::new(0, 0) - direction.offset()
+            );
+            assert_eq!(Dir8::from(direction).offset(), direction.offset());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+
+        for direction in Dir8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.reverse().offset(),
+                Point::new(0, 0) - direction.offset()
+            );
+        }
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthWest.turn_right(), Dir8::North);
+        assert_eq!(Dir8::East.turn_right().turn_right(), Dir8::South);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = Point::new(3i64, -4);
+        assert_eq!(-p, Point::new(-3, 4));
+        assert_eq!(p * 2, Point::new(6, -8));
+        assert_eq!(p * Point::new(2, 3), Point::new(6, -12));
+        assert_eq!(p / 2, Point::new(1, -2));
+        assert_eq!(p / Point::new(3, -2), Point::new(1, 2));
+        assert_eq!(p.signum(), Point::new(1, -1));
+        assert_eq!(Point::new(0i32, 7).signum(), Point::new(0, 1));
+        assert_eq!(p.manhattan_distance(&Point::new(0, 0)), 7);
+        assert_eq!(p.chebyshev_distance(&Point::new(0, 0)), 4);
+
+        let far = Point::new(u64::MAX, 0);
+        assert_eq!(far.manhattan_distance(&Point::new(1, 0)), u64::MAX - 1);
+        assert_eq!(Point::new(2u8, 9).chebyshev_distance(&Point::new(5, 1)), 8);
+
+        let (min, max) = (Point::new(i8::MIN, i8::MIN), Point::new(i8::MAX, i8::MAX));
+        assert_eq!(min.checked_manhattan_distance(&max), None);
+        assert_eq!(far.checked_manhattan_distance(&Point::new(0, 1)), None);
+        assert_eq!(
+            Point::new(-100i8, 0).checked_manhattan_distance(&Point::new(20, 7)),
+            Some(127)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "manhattan distance overflowed")]
+    fn manhattan_distance_overflow() {
+        Point::new(i8::MIN, 0).manhattan_distance(&Point::new(i8::MAX, 0));
+    }
+
+    #[test]
+    fn rotate() {
+        for direction in Direction::ALL {
+            let offset = direction.offset();
+            assert_eq!(offset.rotate_cw(), direction.turn_right().offset());
+            assert_eq!(offset.rotate_ccw(), direction.turn_left().offset());
+        }
+        let p = Point::new(2i64, 5);
+        assert_eq!(p.rotate_cw().rotate_ccw(), p);
+        assert_eq!(p.rotate_cw().rotate_cw(), -p);
+    }
+
+    #[test]
+    fn checked_offset() {
+        let origin = Point::new(0usize, 0);
+        assert_eq!(origin.checked_offset(Direction::North.offset()), None);
+        assert_eq!(
+            origin.checked_offset(Dir8::SouthEast.offset()),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(
+            Point::new(2usize, 1).checked_add_signed(Point::new(-2, 3)),
+            Some(Point::new(0, 4))
+        );
+        assert_eq!(origin.checked_add_signed(Point::new(0, -1)), None);
+    }
+}
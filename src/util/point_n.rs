@@ -0,0 +1,350 @@
+use std::array;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use num::{Signed, Zero};
+
+/// A point with `D` coordinates, for when [`Point`] isn't enough dimensions.
+///
+/// [`Point`]: super::point::Point
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<T, const D: usize>(pub [T; D]);
+
+pub type Point3<T> = PointN<T, 3>;
+pub type Point4<T> = PointN<T, 4>;
+
+impl<T, const D: usize> PointN<T, D> {
+    pub const fn new(coords: [T; D]) -> Self {
+        Self(coords)
+    }
+
+    pub fn map<J>(self, f: impl FnMut(T) -> J) -> PointN<J, D> {
+        PointN(self.0.map(f))
+    }
+
+    pub fn coords(&self) -> &[T; D] {
+        &self.0
+    }
+}
+
+impl<T: Copy> PointN<T, 3> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: Copy + Zero,
+{
+    pub fn origin() -> Self {
+        Self([T::zero(); D])
+    }
+}
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: Copy + Mul<Output = T>,
+{
+    pub fn scale(&self, v: T) -> Self {
+        self.map(|c| c * v)
+    }
+}
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: Copy + Ord + Zero + Sub<Output = T>,
+{
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |sum, i| sum + abs_diff(self.0[i], other.0[i]))
+    }
+
+    /// The largest difference along any axis.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |max, i| max.max(abs_diff(self.0[i], other.0[i])))
+    }
+}
+
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |sum, i| sum + self.0[i] * other.0[i])
+    }
+}
+
+impl<T> PointN<T, 3>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn cross(&self, other: &Self) -> Self {
+        let [a1, a2, a3] = self.0;
+        let [b1, b2, b3] = other.0;
+        Self([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1])
+    }
+}
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: Copy + Signed,
+{
+    /// The neighbours sharing a face, which differ along one axis.
+    pub fn neighbors_face(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors_within(1)
+    }
+
+    /// The neighbours sharing a face or an edge, which differ along at most two axes.
+    pub fn neighbors_edge(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors_within(2)
+    }
+
+    /// Every neighbour, including those only sharing a corner.
+    pub fn neighbors_corner(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors_within(D)
+    }
+
+    /// Neighbours which differ by one along at most `axes` axes, with the offsets counted in
+    /// base 3 so they come out in a fixed order.
+    fn neighbors_within(&self, axes: usize) -> impl Iterator<Item = Self> + '_ {
+        (0..3usize.pow(D as u32)).filter_map(move |mut n| {
+            let offsets: [T; D] = array::from_fn(|_| {
+                let offset = match n % 3 {
+                    0 => -T::one(),
+                    1 => T::zero(),
+                    _ => T::one(),
+                };
+                n /= 3;
+                offset
+            });
+            let moved = offsets.iter().filter(|o| !o.is_zero()).count();
+            (moved > 0 && moved <= axes).then(|| *self + PointN(offsets))
+        })
+    }
+}
+
+impl<T, const D: usize> From<[T; D]> for PointN<T, D> {
+    fn from(coords: [T; D]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<T> From<(T, T, T)> for PointN<T, 3> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<T, const D: usize> Index<usize> for PointN<T, D> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const D: usize> IndexMut<usize> for PointN<T, D> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<T, const D: usize> Add for PointN<T, D>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self(array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<T, const D: usize> AddAssign for PointN<T, D>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T, const D: usize> Sub for PointN<T, D>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self(array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<T, const D: usize> SubAssign for PointN<T, D>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T, const D: usize> Neg for PointN<T, D>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+/// A rotation which keeps the axes aligned, as a matrix with a single `1` or `-1` in each row
+/// and column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation([[i8; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// The 24 orientations of a cube, starting with the identity.
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut rotations = vec![];
+        for axes in permutations {
+            for signs in 0..8 {
+                let matrix = array::from_fn(|row| {
+                    let sign = if signs >> row & 1 == 0 { 1 } else { -1 };
+                    array::from_fn(|column| if column == axes[row] { sign } else { 0 })
+                });
+                let rotation = Rotation(matrix);
+                // Reflections have a determinant of -1
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn matrix(&self) -> [[i8; 3]; 3] {
+        self.0
+    }
+
+    fn determinant(&self) -> i8 {
+        let m = self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// The rotation undoing this one.
+    pub fn inverse(&self) -> Rotation {
+        Rotation(array::from_fn(|row| {
+            array::from_fn(|column| self.0[column][row])
+        }))
+    }
+
+    /// This rotation followed by `other`.
+    pub fn then(&self, other: &Rotation) -> Rotation {
+        Rotation(array::from_fn(|row| {
+            array::from_fn(|column| (0..3).map(|k| other.0[row][k] * self.0[k][column]).sum())
+        }))
+    }
+
+    pub fn apply<T>(&self, point: Point3<T>) -> Point3<T>
+    where
+        T: Copy + Signed,
+    {
+        PointN(array::from_fn(|row| {
+            (0..3).fold(T::zero(), |sum, column| match self.0[row][column] {
+                1 => sum + point.0[column],
+                -1 => sum - point.0[column],
+                _ => sum,
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet as HashSet;
+
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point3::new([1, -2, 3]);
+        let b = Point3::from((4, 0, -1));
+        assert_eq!(a + b, PointN([5, -2, 2]));
+        assert_eq!(a - b, PointN([-3, -2, 4]));
+        assert_eq!(-a, PointN([-1, 2, -3]));
+        assert_eq!(a.scale(2), PointN([2, -4, 6]));
+        assert_eq!((a.x(), a.y(), a.z()), (1, -2, 3));
+        assert_eq!(a.manhattan_distance(&b), 9);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.dot(&b), 1);
+        assert_eq!(a.cross(&b), PointN([2, 13, 8]));
+        assert_eq!(a.cross(&b).dot(&a), 0);
+
+        let unsigned = PointN::<u64, 4>::new([1, 9, 4, 4]);
+        assert_eq!(unsigned.manhattan_distance(&PointN([3, 2, 4, 5])), 10);
+        assert_eq!(PointN::<i32, 4>::origin()[3], 0);
+    }
+
+    #[test]
+    fn neighbors() {
+        let p = Point3::new([0i64, 0, 0]);
+        assert_eq!(p.neighbors_face().count(), 6);
+        assert_eq!(p.neighbors_edge().count(), 18);
+        assert_eq!(p.neighbors_corner().count(), 26);
+        assert!(p.neighbors_face().all(|n| n.manhattan_distance(&p) == 1));
+
+        let hyper = Point4::new([1i64, 1, 1, 1]);
+        assert_eq!(hyper.neighbors_corner().count(), 80);
+        assert_eq!(hyper.neighbors_face().count(), 8);
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        let p = Point3::new([1, 2, 3]);
+        let orientations = rotations.iter().map(|r| r.apply(p)).collect::<HashSet<_>>();
+        assert_eq!(orientations.len(), 24);
+
+        for r in &rotations {
+            assert_eq!(r.inverse().apply(r.apply(p)), p);
+            assert_eq!(r.then(&r.inverse()), Rotation::IDENTITY);
+            for s in &rotations {
+                assert_eq!(r.then(s).apply(p), s.apply(r.apply(p)));
+                assert!(rotations.contains(&r.then(s)));
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::store::{HttpStore, InputError};
+
+/// The parts of a puzzle description useful when starting a new day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: String,
+    /// Only the first part is shown until it has been solved.
+    pub parts: Vec<PuzzlePart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// The contents of each `<pre><code>` block.
+    pub examples: Vec<String>,
+    /// Each `<code><em>` value, the example's answer is usually the last of them.
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PuzzleError {
+    #[error("no puzzle description found")]
+    MissingDescription,
+    #[error(transparent)]
+    Input(#[from] InputError),
+}
+
+impl From<reqwest::Error> for PuzzleError {
+    fn from(e: reqwest::Error) -> Self {
+        PuzzleError::Input(e.into())
+    }
+}
+
+impl Puzzle {
+    pub fn parse(html: &str) -> Result<Puzzle, PuzzleError> {
+        static ARTICLE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap()
+        });
+        static TITLE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").unwrap());
+        static EXAMPLE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+        static ANSWER: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap());
+
+        let articles = ARTICLE
+            .captures_iter(html)
+            .map(|c| c.get(1).unwrap().as_str())
+            .collect::<Vec<_>>();
+        let first = articles.first().ok_or(PuzzleError::MissingDescription)?;
+        let title = TITLE
+            .captures(first)
+            .map(|c| decode_entities(&c[1]))
+            .ok_or(PuzzleError::MissingDescription)?;
+
+        let parts = articles
+            .iter()
+            .map(|article| PuzzlePart {
+                examples: EXAMPLE
+                    .captures_iter(article)
+                    .map(|c| decode_entities(&strip_tags(&c[1])))
+                    .collect(),
+                answers: ANSWER
+                    .captures_iter(article)
+                    .map(|c| decode_entities(&strip_tags(&c[1])))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Puzzle { title, parts })
+    }
+
+    /// The first example of the first part, which most days use for both parts.
+    pub fn example(&self) -> Option<&str> {
+        self.parts.first()?.examples.first().map(String::as_str)
+    }
+
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        let part = self.parts.get(part.checked_sub(1)? as usize)?;
+        part.answers.last().map(String::as_str)
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+    TAG.replace_all(html, "").into_owned()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Downloads the puzzle page, with the second part included once the first is solved.
+pub async fn fetch(http: &HttpStore, year: u32, day: u32) -> Result<Puzzle, PuzzleError> {
+    let res = http
+        .request(reqwest::Method::GET, &format!("/{year}/day/{day}"))?
+        .send()
+        .await?;
+    match res.status() {
+        reqwest::StatusCode::NOT_FOUND => Err(InputError::NotFound { year, day }.into()),
+        status if !status.is_success() => Err(InputError::Status(status).into()),
+        _ => Puzzle::parse(&res.text().await?),
+    }
+}
+
+/// Fills the title, `EXAMPLE` and expected example answers of a day template from the puzzle.
+pub fn fill_template(template: &str, puzzle: &Puzzle) -> String {
+    static TITLE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"(crate::solution!\(\d+, \d+, )"Title""#).unwrap());
+    static EXAMPLE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?s)(\n( *)const EXAMPLE: &str = indoc! \{"\n).*?\n( *"\};)"#).unwrap()
+    });
+
+    let mut filled = TITLE
+        .replace(template, |c: &regex::Captures| {
+            format!("{}\"{}\"", &c[1], escape(&puzzle.title))
+        })
+        .into_owned();
+
+    if let Some(example) = puzzle.example() {
+        filled = EXAMPLE
+            .replace(&filled, |c: &regex::Captures| {
+                let indent = format!("{}    ", &c[2]);
+                let mut block = c[1].to_owned();
+                for line in example.lines() {
+                    if !line.is_empty() {
+                        block.push_str(&indent);
+                        block.push_str(&escape(line));
+                    }
+                    block.push('\n');
+                }
+                block.push_str(&c[3]);
+                block
+            })
+            .into_owned();
+    }
+
+    for part in 1..=2 {
+        if let Some(answer) = puzzle.answer(part) {
+            filled = filled.replace(
+                &format!("part{part}(&parse_input(EXAMPLE)).to_string(), \"0\""),
+                &format!(
+                    "part{part}(&parse_input(EXAMPLE)).to_string(), \"{}\"",
+                    escape(answer)
+                ),
+            );
+        }
+    }
+
+    filled
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    const HISTORIAN_HYSTERIA: &str = include_str!("../../fixtures/puzzle/2024-1.html");
+    const SOME_ASSEMBLY_REQUIRED: &str = include_str!("../../fixtures/puzzle/2015-7.html");
+    const CATHODE_RAY_TUBE: &str = include_str!("../../fixtures/puzzle/2022-10.html");
+
+    #[test]
+    fn parse_both_parts() {
+        let puzzle = Puzzle::parse(HISTORIAN_HYSTERIA).unwrap();
+        assert_eq!(puzzle.title, "Historian Hysteria");
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(
+            puzzle.example(),
+            Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+        );
+        assert_eq!(puzzle.answer(1), Some("11"));
+        assert_eq!(puzzle.answer(2), Some("31"));
+        assert_eq!(puzzle.answer(3), None);
+    }
+
+    #[test]
+    fn parse_first_part_only() {
+        let puzzle = Puzzle::parse(SOME_ASSEMBLY_REQUIRED).unwrap();
+        assert_eq!(puzzle.title, "Some Assembly Required");
+        assert_eq!(puzzle.parts.len(), 1);
+        assert_eq!(puzzle.parts[0].examples.len(), 2);
+        assert!(puzzle
+            .example()
+            .unwrap()
+            .starts_with("123 -> x\n456 -> y\n"));
+        // The question is emphasised but isn't an example answer
+        assert_eq!(puzzle.answer(1), None);
+        assert_eq!(puzzle.answer(2), None);
+    }
+
+    #[test]
+    fn parse_emphasis_in_examples() {
+        let puzzle = Puzzle::parse(CATHODE_RAY_TUBE).unwrap();
+        assert_eq!(puzzle.answer(1), Some("13140"));
+        assert_eq!(
+            puzzle.parts[1].examples[1],
+            "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n"
+        );
+        assert!(matches!(
+            Puzzle::parse("<html></html>"),
+            Err(PuzzleError::MissingDescription)
+        ));
+    }
+
+    #[test]
+    fn fill_day_template() {
+        let template = include_str!("../day_template.rs");
+        let puzzle = Puzzle::parse(HISTORIAN_HYSTERIA).unwrap();
+        let filled = fill_template(template, &puzzle);
+
+        assert!(filled.contains(r#"crate::solution!(2023, 1, "Historian Hysteria", "#));
+        assert!(filled.contains(concat!(
+            "    const EXAMPLE: &str = indoc! {\"\n",
+            "        3   4\n        4   3\n        2   5\n        1   3\n        3   9\n        3   3\n",
+            "    \"};\n",
+        )));
+        assert!(filled.contains(r#"part1(&parse_input(EXAMPLE)).to_string(), "11""#));
+        assert!(filled.contains(r#"part2(&parse_input(EXAMPLE)).to_string(), "31""#));
+    }
+
+    #[test]
+    fn escape_examples() {
+        let puzzle = Puzzle {
+            title: "Say \"Hi\"".to_owned(),
+            parts: vec![PuzzlePart {
+                examples: vec!["a\\b \"c\"\n\nd\n".to_owned()],
+                answers: vec![],
+            }],
+        };
+        let filled = fill_template(include_str!("../day_template.rs"), &puzzle);
+        assert!(filled.contains(r#""Say \"Hi\"""#));
+        assert!(filled.contains("        a\\\\b \\\"c\\\"\n\n        d\n    \"};"));
+        assert!(filled.contains(r#"part1(&parse_input(EXAMPLE)).to_string(), "0""#));
+    }
+
+    #[tokio::test]
+    async fn fetch_puzzle() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2024/day/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(HISTORIAN_HYSTERIA))
+            .mount(&server)
+            .await;
+
+        let http = HttpStore::new(server.uri(), Some("session=abc".to_owned()));
+        let puzzle = fetch(&http, 2024, 1).await.unwrap();
+        assert_eq!(puzzle.title, "Historian Hysteria");
+        assert!(matches!(
+            fetch(&http, 2024, 2).await,
+            Err(PuzzleError::Input(InputError::NotFound { .. }))
+        ));
+    }
+}
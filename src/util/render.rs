@@ -0,0 +1,473 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use rustc_hash::FxHashMap as HashMap;
+
+use super::grid::{Grid, GridView};
+use super::point::Point;
+use super::sparse_grid::SparseGrid;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [220, 50, 47];
+pub const GREEN: Rgb = [133, 153, 0];
+pub const YELLOW: Rgb = [181, 137, 0];
+pub const BLUE: Rgb = [38, 139, 210];
+
+/// A rectangle of cells which can be drawn.
+pub trait Cells {
+    type Cell;
+
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn cell(&self, point: Point<usize>) -> &Self::Cell;
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn cell(&self, point: Point<usize>) -> &T {
+        &self[point]
+    }
+}
+
+impl<T> Cells for GridView<'_, T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        GridView::width(self)
+    }
+
+    fn height(&self) -> usize {
+        GridView::height(self)
+    }
+
+    fn cell(&self, point: Point<usize>) -> &T {
+        &self[point]
+    }
+}
+
+/// Covers the grid's bounds, so positions are relative to the smallest corner.
+impl<T> Cells for SparseGrid<T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    fn height(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    fn cell(&self, point: Point<usize>) -> &T {
+        let (min, _) = self
+            .bounds()
+            .unwrap_or((Point::new(0, 0), Point::new(0, 0)));
+        self.get(Point::new(min.x + point.x as i64, min.y + point.y as i64))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenderError {
+    #[error("{width}x{height} is too large for a gif")]
+    TooLarge { width: usize, height: usize },
+    #[error("frame {frame} has more than 256 colours")]
+    TooManyColours { frame: usize },
+    #[error("frame {frame} is {found:?} but the first frame is {expected:?}")]
+    FrameSize {
+        frame: usize,
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    #[error("unknown image format for {0}, expected .ppm or .png")]
+    UnknownFormat(String),
+    #[error(transparent)]
+    Png(#[from] png::EncodingError),
+    #[error(transparent)]
+    Gif(#[from] gif::EncodingError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Draws each cell as a character, one line per row.
+pub fn text<C: Cells>(cells: &C, mut glyph: impl FnMut(&C::Cell) -> char) -> String {
+    let mut s = String::with_capacity((cells.width() + 1) * cells.height());
+    for y in 0..cells.height() {
+        for x in 0..cells.width() {
+            s.push(glyph(cells.cell(Point::new(x, y))));
+        }
+        s.push('\n');
+    }
+    s
+}
+
+/// A character with optional 24-bit terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub c: char,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl Glyph {
+    pub fn fg(self, rgb: Rgb) -> Self {
+        Glyph {
+            fg: Some(rgb),
+            ..self
+        }
+    }
+
+    pub fn bg(self, rgb: Rgb) -> Self {
+        Glyph {
+            bg: Some(rgb),
+            ..self
+        }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(c: char) -> Self {
+        Glyph {
+            c,
+            fg: None,
+            bg: None,
+        }
+    }
+}
+
+/// Draws cells for a terminal, `glyph` is given each cell's position so points or paths can
+/// be highlighted.
+pub fn ansi<C: Cells>(cells: &C, mut glyph: impl FnMut(Point<usize>, &C::Cell) -> Glyph) -> String {
+    let mut s = String::new();
+    for y in 0..cells.height() {
+        let mut styled = false;
+        for x in 0..cells.width() {
+            let point = Point::new(x, y);
+            let Glyph { c, fg, bg } = glyph(point, cells.cell(point));
+            if styled {
+                s.push_str("\x1b[0m");
+            }
+            if let Some([r, g, b]) = fg {
+                write!(s, "\x1b[38;2;{r};{g};{b}m").unwrap();
+            }
+            if let Some([r, g, b]) = bg {
+                write!(s, "\x1b[48;2;{r};{g};{b}m").unwrap();
+            }
+            styled = fg.is_some() || bg.is_some();
+            s.push(c);
+        }
+        if styled {
+            s.push_str("\x1b[0m");
+        }
+        s.push('\n');
+    }
+    s
+}
+
+/// An RGB image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: background.repeat(width * height),
+        }
+    }
+
+    /// Draws each cell as a `scale` pixel square.
+    pub fn from_cells<C: Cells>(
+        cells: &C,
+        scale: usize,
+        mut colour: impl FnMut(&C::Cell) -> Rgb,
+    ) -> Self {
+        let mut image = Image::new(cells.width() * scale, cells.height() * scale, BLACK);
+        for y in 0..cells.height() {
+            for x in 0..cells.width() {
+                let point = Point::new(x, y);
+                image.fill_cell(point, scale, colour(cells.cell(point)));
+            }
+        }
+        image
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        let i = (y * self.width + x) * 3;
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]]
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, rgb: Rgb) {
+        let i = (y * self.width + x) * 3;
+        self.pixels[i..i + 3].copy_from_slice(&rgb);
+    }
+
+    /// Colours the square drawn for a cell by [`Image::from_cells`], such as to highlight a path.
+    pub fn fill_cell(&mut self, cell: Point<usize>, scale: usize, rgb: Rgb) {
+        for y in cell.y * scale..(cell.y + 1) * scale {
+            for x in cell.x * scale..(cell.x + 1) * scale {
+                self.set_pixel(x, y, rgb);
+            }
+        }
+    }
+
+    pub fn write_ppm(&self, mut w: impl Write) -> Result<(), RenderError> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.pixels)?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, w: impl Write) -> Result<(), RenderError> {
+        let mut encoder = png::Encoder::new(w, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Saves as a PPM or PNG depending on the extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), RenderError> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|e| e.to_str());
+        if !matches!(extension, Some("ppm" | "png")) {
+            return Err(RenderError::UnknownFormat(path.display().to_string()));
+        }
+
+        let mut file = BufWriter::new(std::fs::File::create(path)?);
+        match extension {
+            Some("ppm") => self.write_ppm(&mut file)?,
+            _ => self.write_png(&mut file)?,
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes images as the frames of a looping animated GIF, such as each step of a simulation.
+pub struct Recorder<W: Write> {
+    encoder: gif::Encoder<W>,
+    size: (usize, usize),
+    /// In hundredths of a second.
+    delay: u16,
+    frames: usize,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(w: W, width: usize, height: usize, delay: Duration) -> Result<Self, RenderError> {
+        let too_large = || RenderError::TooLarge { width, height };
+        let mut encoder = gif::Encoder::new(
+            w,
+            width.try_into().map_err(|_| too_large())?,
+            height.try_into().map_err(|_| too_large())?,
+            &[],
+        )?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Recorder {
+            encoder,
+            size: (width, height),
+            delay: (delay.as_millis() / 10).try_into().unwrap_or(u16::MAX),
+            frames: 0,
+        })
+    }
+
+    pub fn frame(&mut self, image: &Image) -> Result<(), RenderError> {
+        let frame = self.frames;
+        if (image.width, image.height) != self.size {
+            return Err(RenderError::FrameSize {
+                frame,
+                expected: self.size,
+                found: (image.width, image.height),
+            });
+        }
+
+        let mut palette = HashMap::<Rgb, u8>::default();
+        let mut indices = Vec::with_capacity(image.width * image.height);
+        for rgb in image.pixels.chunks_exact(3) {
+            let rgb = [rgb[0], rgb[1], rgb[2]];
+            let next = palette.len();
+            let index = *palette.entry(rgb).or_insert(next as u8);
+            if palette.len() > 256 {
+                return Err(RenderError::TooManyColours { frame });
+            }
+            indices.push(index);
+        }
+
+        let mut colours = vec![0; palette.len() * 3];
+        for (rgb, &index) in &palette {
+            colours[index as usize * 3..index as usize * 3 + 3].copy_from_slice(rgb);
+        }
+
+        let mut frame = gif::Frame::from_palette_pixels(
+            self.size.0 as u16,
+            self.size.1 as u16,
+            indices,
+            colours,
+            None,
+        );
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Finishes the animation, returning the writer.
+    pub fn finish(self) -> Result<W, RenderError> {
+        Ok(self.encoder.into_inner()?)
+    }
+}
+
+impl Recorder<BufWriter<std::fs::File>> {
+    pub fn create(
+        path: impl AsRef<Path>,
+        width: usize,
+        height: usize,
+        delay: Duration,
+    ) -> Result<Self, RenderError> {
+        let file = BufWriter::new(std::fs::File::create(path)?);
+        Recorder::new(file, width, height, delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lights() -> Grid<bool> {
+        Grid::parse_with("#.\n.#\n#.\n", |c| Ok::<_, ()>(c == '#')).unwrap()
+    }
+
+    fn light(&on: &bool) -> char {
+        if on {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    #[test]
+    fn render_text() {
+        assert_eq!(text(&lights(), light), "#.\n.#\n#.\n");
+
+        let grid = lights();
+        assert_eq!(text(&grid.view(Point::new(0, 1), 2, 2), light), ".#\n#.\n");
+
+        let mut sparse = SparseGrid::new('.');
+        sparse.insert(Point::new(-1, -1), 'a');
+        sparse.insert(Point::new(1, 0), 'b');
+        assert_eq!(text(&sparse, |&c| c), "a..\n..b\n");
+    }
+
+    #[test]
+    fn render_ansi() {
+        let path = [Point::new(0, 0), Point::new(0, 1)];
+        let rendered = ansi(&lights(), |point, on| {
+            let glyph = Glyph::from(light(on));
+            if path.contains(&point) {
+                glyph.bg(RED)
+            } else {
+                glyph
+            }
+        });
+        assert_eq!(
+            rendered,
+            "\x1b[48;2;220;50;47m#\x1b[0m.\n\x1b[48;2;220;50;47m.\x1b[0m#\n#.\n"
+        );
+    }
+
+    #[test]
+    fn render_images() {
+        let mut image = Image::from_cells(&lights(), 2, |&on| if on { WHITE } else { BLACK });
+        assert_eq!((image.width(), image.height()), (4, 6));
+        assert_eq!(image.pixel(1, 1), WHITE);
+        assert_eq!(image.pixel(2, 1), BLACK);
+        image.fill_cell(Point::new(1, 0), 2, BLUE);
+        assert_eq!(image.pixel(3, 1), BLUE);
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 6\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 6 * 3);
+
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let dir = tempfile::tempdir().unwrap();
+        image.save(dir.path().join("lights.png")).unwrap();
+        assert!(matches!(
+            image.save(dir.path().join("lights.bmp")),
+            Err(RenderError::UnknownFormat(_))
+        ));
+    }
+
+    #[test]
+    fn record_gif() {
+        let mut recorder = Recorder::new(vec![], 2, 3, Duration::from_millis(100)).unwrap();
+        let mut grid = lights();
+        for _ in 0..3 {
+            recorder
+                .frame(&Image::from_cells(
+                    &grid,
+                    1,
+                    |&on| if on { GREEN } else { BLACK },
+                ))
+                .unwrap();
+            grid = grid.flip_horizontal();
+        }
+        assert!(matches!(
+            recorder.frame(&Image::new(3, 3, BLACK)),
+            Err(RenderError::FrameSize { frame: 3, .. })
+        ));
+        assert_eq!(recorder.frames(), 3);
+
+        let gif = recorder.finish().unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+
+        let mut rainbow = Image::new(17, 17, BLACK);
+        for i in 0..17 * 17 {
+            rainbow.set_pixel(i % 17, i / 17, [i as u8, (i / 256) as u8, 0]);
+        }
+        let mut recorder = Recorder::new(vec![], 17, 17, Duration::ZERO).unwrap();
+        assert!(matches!(
+            recorder.frame(&rainbow),
+            Err(RenderError::TooManyColours { frame: 0 })
+        ));
+    }
+}
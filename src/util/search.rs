@@ -0,0 +1,336 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::grid::Grid;
+use super::point::Point;
+
+/// The shortest distances found by a search and how each node was reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    pub distances: HashMap<N, C>,
+    /// The node each node was first reached from, start nodes have none.
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The nodes from a start to `goal` inclusive, or `None` when `goal` wasn't reached.
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        self.distances.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search from every start, counting each step as one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths {
+        distances: HashMap::default(),
+        predecessors: HashMap::default(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Lowest cost paths from every start, where `successors` gives each neighbour and the cost
+/// of moving to it. Costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        distances: HashMap::default(),
+        predecessors: HashMap::default(),
+    };
+    let mut nodes = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), C::zero()).is_none() {
+            heap.push(Reverse((C::zero(), nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if paths.distances[&node] < cost {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.distances.get(&next).is_none_or(|&c| next_cost < c) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.predecessors.insert(next.clone(), node.clone());
+                heap.push(Reverse((next_cost, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// The lowest cost path from `start` to the first node satisfying `goal`, with its cost.
+///
+/// `heuristic` must never overestimate the remaining cost for the path to be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        distances: HashMap::default(),
+        predecessors: HashMap::default(),
+    };
+    paths.distances.insert(start.clone(), C::zero());
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), C::zero(), 0)));
+    let mut nodes = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if paths.distances[&node] < cost {
+            continue;
+        }
+        if goal(&node) {
+            return Some((paths.path(&node).unwrap(), cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.distances.get(&next).is_none_or(|&c| next_cost < c) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.predecessors.insert(next.clone(), node.clone());
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth first search, returning each reachable node once in the order it was visited.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::default();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let mut next = successors(&node)
+            .into_iter()
+            .filter(|n| !visited.contains(n))
+            .collect::<Vec<_>>();
+        // Visit successors in the order they're given
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// Every node reachable from `start`, including itself.
+pub fn flood_fill<N, I>(start: N, successors: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    dfs(start, successors).into_iter().collect()
+}
+
+/// Successors over the orthogonal neighbours of a grid cell, `passable(from, to)` decides
+/// whether a step can be taken.
+pub fn grid4<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T, &T) -> bool + 'a,
+) -> impl FnMut(&Point<usize>) -> Vec<Point<usize>> + 'a {
+    move |&point| {
+        let from = &grid[point];
+        grid.neighbors4(point)
+            .filter(|(_, to)| passable(from, to))
+            .map(|(next, _)| next)
+            .collect()
+    }
+}
+
+/// Like [`grid4`], including diagonal neighbours.
+pub fn grid8<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T, &T) -> bool + 'a,
+) -> impl FnMut(&Point<usize>) -> Vec<Point<usize>> + 'a {
+    move |&point| {
+        let from = &grid[point];
+        grid.neighbors8(point)
+            .filter(|(_, to)| passable(from, to))
+            .map(|(next, _)| next)
+            .collect()
+    }
+}
+
+/// Weighted successors over the orthogonal neighbours of a grid cell for [`dijkstra`] and
+/// [`astar`], `cost(from, to)` is `None` when the step can't be taken.
+pub fn grid4_weighted<'a, T, C>(
+    grid: &'a Grid<T>,
+    cost: impl Fn(&T, &T) -> Option<C> + 'a,
+) -> impl FnMut(&Point<usize>) -> Vec<(Point<usize>, C)> + 'a {
+    move |&point| {
+        let from = &grid[point];
+        grid.neighbors4(point)
+            .filter_map(|(next, to)| Some((next, cost(from, to)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const MAZE: &str = indoc! {"
+        S.#.....
+        .##.###.
+        ....#...
+        .####.#.
+        ......#E
+    "};
+
+    fn find(grid: &Grid<char>, c: char) -> Point<usize> {
+        grid.find(|&v| v == c).unwrap()
+    }
+
+    #[test]
+    fn bfs_grid() {
+        let grid = Grid::parse(MAZE);
+        let (start, end) = (find(&grid, 'S'), find(&grid, 'E'));
+        let paths = bfs([start], grid4(&grid, |_, &to| to != '#'));
+
+        assert_eq!(paths.distance(&end), Some(15));
+        let path = paths.path(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        for (a, b) in path.iter().zip(&path[1..]) {
+            assert_eq!(a.manhattan_distance(b), 1);
+            assert_ne!(grid[*b], '#');
+        }
+
+        assert_eq!(paths.path(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn bfs_multiple_starts() {
+        let paths = bfs([0, 10], |&n: &i32| {
+            [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n))
+        });
+        assert_eq!(paths.distance(&5), Some(5));
+        assert_eq!(paths.distance(&8), Some(2));
+        assert_eq!(paths.path(&10), Some(vec![10]));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid = Grid::parse_bytes(indoc! {"
+            1163751742
+            1381373672
+            2136511328
+            3694931569
+            7463417111
+            1319128137
+            1359912421
+            3125421639
+            1293138521
+            2311944581
+        "});
+        let end = Point::new(grid.width() - 1, grid.height() - 1);
+        let risk = |_: &u8, &to: &u8| Some((to - b'0') as u32);
+
+        let paths = dijkstra([Point::new(0, 0)], grid4_weighted(&grid, risk));
+        assert_eq!(paths.distance(&end), Some(40));
+
+        let (path, cost) = astar(
+            Point::new(0, 0),
+            grid4_weighted(&grid, risk),
+            |p| p.manhattan_distance(&end) as u32,
+            |&p| p == end,
+        )
+        .unwrap();
+        assert_eq!(cost, 40);
+        assert_eq!(path, paths.path(&end).unwrap());
+        let total: u32 = path[1..].iter().map(|&p| (grid[p] - b'0') as u32).sum();
+        assert_eq!(total, 40);
+
+        let evens = |&n: &u32| (n < 10).then_some((n + 2, 1));
+        assert_eq!(astar(0, evens, |_| 0, |&n| n == 5), None);
+    }
+
+    #[test]
+    fn dfs_and_flood_fill() {
+        let edges = HashMap::from_iter([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (5, vec![1])]);
+        let successors = |n: &i32| edges.get(n).cloned().unwrap_or_default();
+        assert_eq!(dfs(1, successors), [1, 2, 4, 3]);
+        assert_eq!(flood_fill(1, successors), HashSet::from_iter([1, 2, 3, 4]));
+
+        let grid = Grid::parse("aab\nabb\n");
+        let region = flood_fill(Point::new(0, 0), grid8(&grid, |a, b| a == b));
+        assert_eq!(region.len(), 3);
+        assert!(!region.contains(&Point::new(2, 0)));
+    }
+}
@@ -0,0 +1,217 @@
+use rustc_hash::FxHashMap as HashMap;
+
+use super::grid::Grid;
+use super::point::{Dir8, Direction, Point};
+
+/// An unbounded grid which only stores the cells that have been set, every other cell holds
+/// the default value.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<i64>, T>,
+    default: T,
+    bounds: Option<(Point<i64>, Point<i64>)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::default(),
+            default,
+            bounds: None,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest and largest corners of every cell which has been set, inclusive.
+    ///
+    /// Removing cells doesn't shrink the bounds.
+    #[inline]
+    pub fn bounds(&self) -> Option<(Point<i64>, Point<i64>)> {
+        self.bounds
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point<i64>) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Whether the cell has been set, even if to the default value.
+    #[inline]
+    pub fn contains(&self, point: Point<i64>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn insert(&mut self, point: Point<i64>, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point<i64>) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Every cell which has been set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i64>, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The orthogonal neighbours of `point`, clockwise from north.
+    pub fn neighbors4(&self, point: Point<i64>) -> impl Iterator<Item = (Point<i64>, &T)> {
+        Direction::ALL.into_iter().map(move |direction| {
+            let neighbor = point + direction.offset();
+            (neighbor, self.get(neighbor))
+        })
+    }
+
+    /// The orthogonal and diagonal neighbours of `point`, clockwise from north.
+    pub fn neighbors8(&self, point: Point<i64>) -> impl Iterator<Item = (Point<i64>, &T)> {
+        Dir8::ALL.into_iter().map(move |direction| {
+            let neighbor = point + direction.offset();
+            (neighbor, self.get(neighbor))
+        })
+    }
+
+    fn extend_bounds(&mut self, point: Point<i64>) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone,
+{
+    /// The cell's value, setting it to the default first if it hasn't been set.
+    pub fn get_mut(&mut self, point: Point<i64>) -> &mut T {
+        self.extend_bounds(point);
+        self.cells
+            .entry(point)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// A dense copy covering the bounds, with the position of its top left cell.
+    pub fn to_grid(&self) -> (Grid<T>, Point<i64>) {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::filled(0, 0, self.default.clone()), Point::new(0, 0));
+        };
+
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let mut grid = Grid::filled(width, height, self.default.clone());
+        for (&point, value) in &self.cells {
+            grid[((point.x - min.x) as usize, (point.y - min.y) as usize)] = value.clone();
+        }
+        (grid, min)
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Copies the cells of `grid` which aren't `default`, with its top left cell at `origin`.
+    pub fn from_grid(grid: &Grid<T>, origin: Point<i64>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        for (value, point) in grid.iter() {
+            if *value != sparse.default {
+                let point = Point::new(origin.x + point.x as i64, origin.y + point.y as i64);
+                sparse.insert(point, value.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl SparseGrid<u8> {
+    /// Renders the bounds in the same form as [`Grid::display`].
+    pub fn display(&self) -> String {
+        self.to_grid().0.display()
+    }
+}
+
+impl<T> std::ops::Index<Point<i64>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<i64>) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+impl<T> std::ops::IndexMut<Point<i64>> for SparseGrid<T>
+where
+    T: Clone,
+{
+    fn index_mut(&mut self, point: Point<i64>) -> &mut Self::Output {
+        self.get_mut(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_bound_cells() {
+        let mut grid = SparseGrid::new(0u8);
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid[Point::new(-100, 100)], 0);
+
+        grid.insert(Point::new(-2, 1), 3);
+        grid[Point::new(1, -1)] += 1;
+        grid[Point::new(1, -1)] += 1;
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid[Point::new(1, -1)], 2);
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 1))));
+        assert_eq!(grid.display(), "...2\n....\n3...\n");
+
+        assert_eq!(grid.remove(Point::new(-2, 1)), Some(3));
+        assert!(!grid.contains(Point::new(-2, 1)));
+        assert_eq!(grid.bounds().unwrap().0, Point::new(-2, -1));
+    }
+
+    #[test]
+    fn neighbors() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(Point::new(0, -1), 'n');
+        grid.insert(Point::new(-1, -1), 'x');
+        let around = |n8: bool| -> String {
+            if n8 {
+                grid.neighbors8(Point::new(0, 0)).map(|(_, &c)| c).collect()
+            } else {
+                grid.neighbors4(Point::new(0, 0)).map(|(_, &c)| c).collect()
+            }
+        };
+        assert_eq!(around(false), "n...");
+        assert_eq!(around(true), "n......x");
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let dense = Grid::parse_bytes("#..\n.#.\n..#\n");
+        let sparse = SparseGrid::from_grid(&dense, Point::new(5, -3), b'.');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(sparse[Point::new(6, -2)], b'#');
+        assert_eq!(
+            sparse.bounds(),
+            Some((Point::new(5, -3), Point::new(7, -1)))
+        );
+
+        let (grid, origin) = sparse.to_grid();
+        assert_eq!(origin, Point::new(5, -3));
+        assert_eq!(grid.display(), dense.display());
+    }
+}
@@ -0,0 +1,554 @@
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rustc_hash::FxHashMap;
+
+pub const DATA_PATH: &str = "data/";
+pub const ENCRYPTED_PATH: &str = "data-encrypted/";
+pub const AGE_KEY_FILE: &str = ".age-key";
+pub const AGE_PUBLIC_KEY: &str = "age140duwh987gm560y9ka363t9jy2n476m7q9up0mqkuew6ptxer4pq8yejqr";
+pub const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/grant0417/advent by grant@gurvis.net";
+
+#[derive(Debug, thiserror::Error)]
+pub enum InputError {
+    #[error("{year} day {day} is not an Advent of Code puzzle")]
+    InvalidDate { year: u32, day: u32 },
+    #[error("no input available for {year} day {day}")]
+    NotFound { year: u32, day: u32 },
+    #[error("no session cookie, set AOC_COOKIE in the environment or .env")]
+    MissingCookie,
+    #[error("no age identity, put the secret key in .age-key")]
+    MissingKey,
+    #[error("invalid age key: {0}")]
+    InvalidKey(&'static str),
+    #[error(transparent)]
+    Decrypt(#[from] age::DecryptError),
+    #[error(transparent)]
+    Encrypt(#[from] age::EncryptError),
+    #[error("server responded with {0}")]
+    Status(reqwest::StatusCode),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl InputError {
+    /// Whether the store simply doesn't have the input, so another store could be tried.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, InputError::NotFound { .. } | InputError::MissingKey)
+    }
+}
+
+/// A path relative to the repository root, so inputs and keys are found whatever the working
+/// directory.
+pub fn project_path(path: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+pub fn validate(year: u32, day: u32) -> Result<(), InputError> {
+    if year >= 2015 && (1..=25).contains(&day) {
+        Ok(())
+    } else {
+        Err(InputError::InvalidDate { year, day })
+    }
+}
+
+/// Somewhere puzzle inputs can be loaded from, and optionally saved to.
+pub trait InputStore {
+    fn get(&self, year: u32, day: u32) -> impl Future<Output = Result<String, InputError>> + Send;
+
+    /// Saves an input fetched from elsewhere, stores which can't hold inputs ignore it.
+    fn put(
+        &self,
+        _year: u32,
+        _day: u32,
+        _input: &str,
+    ) -> impl Future<Output = Result<(), InputError>> + Send {
+        async { Ok(()) }
+    }
+}
+
+/// Inputs stored as `{root}/{year}/day{day}.txt`.
+pub struct FsStore {
+    root: PathBuf,
+}
+
+impl FsStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FsStore { root: root.into() }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+}
+
+impl Default for FsStore {
+    fn default() -> Self {
+        FsStore::new(project_path(DATA_PATH))
+    }
+}
+
+impl InputStore for FsStore {
+    async fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        match tokio::fs::read_to_string(self.path(year, day)).await {
+            Ok(input) => Ok(input),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Err(InputError::NotFound { year, day })
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn put(&self, year: u32, day: u32, input: &str) -> Result<(), InputError> {
+        let path = self.path(year, day);
+        tokio::fs::create_dir_all(path.parent().unwrap()).await?;
+        tokio::fs::write(path, input).await?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryStore {
+    inputs: Mutex<FxHashMap<(u32, u32), String>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(self, year: u32, day: u32, input: impl Into<String>) -> Self {
+        self.inputs
+            .lock()
+            .unwrap()
+            .insert((year, day), input.into());
+        self
+    }
+}
+
+impl InputStore for MemoryStore {
+    async fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let inputs = self.inputs.lock().unwrap();
+        inputs
+            .get(&(year, day))
+            .cloned()
+            .ok_or(InputError::NotFound { year, day })
+    }
+
+    async fn put(&self, year: u32, day: u32, input: &str) -> Result<(), InputError> {
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.insert((year, day), input.to_owned());
+        Ok(())
+    }
+}
+
+/// Inputs encrypted with age as `{root}/{year}/day{day}.txt.age`, so they can be committed.
+///
+/// Anyone can add inputs with the public key, decrypting them needs the identity in `.age-key`.
+pub struct AgeStore {
+    root: PathBuf,
+    identity: Result<age::x25519::Identity, KeyError>,
+    recipient: age::x25519::Recipient,
+}
+
+/// Why the identity couldn't be read, kept until an input needs decrypting.
+#[derive(Debug, Clone)]
+enum KeyError {
+    Missing,
+    Invalid(&'static str),
+    Unreadable(std::io::ErrorKind, String),
+}
+
+impl From<&KeyError> for InputError {
+    fn from(e: &KeyError) -> Self {
+        match e {
+            KeyError::Missing => InputError::MissingKey,
+            KeyError::Invalid(reason) => InputError::InvalidKey(reason),
+            KeyError::Unreadable(kind, message) => {
+                std::io::Error::new(*kind, message.clone()).into()
+            }
+        }
+    }
+}
+
+impl AgeStore {
+    pub fn new(
+        root: impl Into<PathBuf>,
+        identity: Option<age::x25519::Identity>,
+        recipient: age::x25519::Recipient,
+    ) -> Self {
+        AgeStore {
+            root: root.into(),
+            identity: identity.ok_or(KeyError::Missing),
+            recipient,
+        }
+    }
+
+    /// Reads the identity from `key_path`, a key which is missing or can't be read is only
+    /// reported once an input needs decrypting.
+    pub fn with_key_file(
+        root: impl Into<PathBuf>,
+        key_path: impl AsRef<Path>,
+        recipient: age::x25519::Recipient,
+    ) -> Self {
+        let identity = read_identity(key_path).map_err(|e| match e {
+            InputError::MissingKey => KeyError::Missing,
+            InputError::InvalidKey(reason) => KeyError::Invalid(reason),
+            InputError::Io(e) => KeyError::Unreadable(e.kind(), e.to_string()),
+            e => unreachable!("unexpected error reading an identity: {e}"),
+        });
+        AgeStore {
+            root: root.into(),
+            identity,
+            recipient,
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day}.txt.age"))
+    }
+}
+
+impl Default for AgeStore {
+    fn default() -> Self {
+        AgeStore::with_key_file(
+            project_path(ENCRYPTED_PATH),
+            project_path(AGE_KEY_FILE),
+            AGE_PUBLIC_KEY.parse().unwrap(),
+        )
+    }
+}
+
+impl InputStore for AgeStore {
+    async fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let encrypted = match tokio::fs::read(self.path(year, day)).await {
+            Ok(encrypted) => encrypted,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(InputError::NotFound { year, day })
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let identity = self.identity.as_ref().map_err(InputError::from)?;
+        let decrypted = age::decrypt(identity, &encrypted)?;
+        String::from_utf8(decrypted)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e).into())
+    }
+
+    async fn put(&self, year: u32, day: u32, input: &str) -> Result<(), InputError> {
+        let encrypted = age::encrypt(&self.recipient, input.as_bytes())?;
+        let path = self.path(year, day);
+        tokio::fs::create_dir_all(path.parent().unwrap()).await?;
+        tokio::fs::write(path, encrypted).await?;
+        Ok(())
+    }
+}
+
+/// Reads an identity file as written by `age-keygen`, comments are skipped.
+pub fn read_identity(path: impl AsRef<Path>) -> Result<age::x25519::Identity, InputError> {
+    let key = match std::fs::read_to_string(path) {
+        Ok(key) => key,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(InputError::MissingKey),
+        Err(e) => return Err(e.into()),
+    };
+    key.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .ok_or(InputError::MissingKey)?
+        .parse()
+        .map_err(InputError::InvalidKey)
+}
+
+/// Fetches inputs from `{base_url}/{year}/day/{day}/input` with the session cookie.
+pub struct HttpStore {
+    base_url: String,
+    cookie: Option<String>,
+    client: reqwest::Client,
+}
+
+impl HttpStore {
+    pub fn new(base_url: impl Into<String>, cookie: Option<String>) -> Self {
+        HttpStore {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            cookie,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// The real site, or `AOC_BASE_URL` when set, using the cookie from [`cookie`].
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_owned());
+        HttpStore::new(base_url, cookie())
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Builds a request to a path under the base url with the session cookie set.
+    pub fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+    ) -> Result<reqwest::RequestBuilder, InputError> {
+        let cookie = self.cookie.as_ref().ok_or(InputError::MissingCookie)?;
+        Ok(self
+            .client
+            .request(method, format!("{}{path}", self.base_url))
+            .header("Cookie", cookie)
+            .header("User-Agent", USER_AGENT))
+    }
+}
+
+impl InputStore for HttpStore {
+    async fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let res = self
+            .request(reqwest::Method::GET, &format!("/{year}/day/{day}/input"))?
+            .send()
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::NOT_FOUND => Err(InputError::NotFound { year, day }),
+            status if !status.is_success() => Err(InputError::Status(status)),
+            _ => Ok(res.text().await?),
+        }
+    }
+}
+
+/// Reads from `cache` first, falling back to `remote` when the input is missing and saving
+/// what it returns.
+///
+/// An input the cache has but can't decrypt is fetched without being saved, as it would only
+/// overwrite the existing file.
+pub struct Cached<C, R> {
+    pub cache: C,
+    pub remote: R,
+}
+
+impl<C: InputStore + Sync, R: InputStore + Sync> InputStore for Cached<C, R> {
+    async fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        match self.cache.get(year, day).await {
+            Err(e) if e.is_missing() => {
+                let input = self.remote.get(year, day).await?;
+                if !matches!(e, InputError::MissingKey) {
+                    self.cache.put(year, day, &input).await?;
+                }
+                Ok(input)
+            }
+            result => result,
+        }
+    }
+}
+
+pub type DefaultStore = Cached<FsStore, Cached<AgeStore, HttpStore>>;
+
+/// `data/`, then the encrypted inputs in `data-encrypted/`, then adventofcode.com.
+pub fn default_store() -> DefaultStore {
+    Cached {
+        cache: FsStore::default(),
+        remote: Cached {
+            cache: AgeStore::default(),
+            remote: HttpStore::from_env(),
+        },
+    }
+}
+
+/// The `Cookie` header from `AOC_COOKIE` (`session=...`), either set in the environment or
+/// in `.env`.
+pub fn cookie() -> Option<String> {
+    if let Ok(cookie) = std::env::var("AOC_COOKIE") {
+        return Some(cookie);
+    }
+
+    let env = std::fs::read_to_string(project_path(".env")).ok()?;
+    env.lines()
+        .find_map(|line| line.strip_prefix("AOC_COOKIE"))
+        .and_then(|rest| rest.trim_start().strip_prefix('='))
+        .map(|cookie| cookie.trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_store() {
+        let store = MemoryStore::new().with(2024, 1, "3   4\n");
+        assert_eq!(store.get(2024, 1).await.unwrap(), "3   4\n");
+        assert!(matches!(
+            store.get(2024, 2).await,
+            Err(InputError::NotFound { year: 2024, day: 2 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn fs_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FsStore::new(dir.path());
+        assert!(matches!(
+            store.get(2023, 5).await,
+            Err(InputError::NotFound { .. })
+        ));
+
+        store.put(2023, 5, "seeds: 79 14").await.unwrap();
+        assert!(dir.path().join("2023/day5.txt").exists());
+        assert_eq!(store.get(2023, 5).await.unwrap(), "seeds: 79 14");
+    }
+
+    #[test]
+    fn validate_date() {
+        assert!(validate(2015, 1).is_ok());
+        assert!(validate(2024, 25).is_ok());
+        assert!(matches!(
+            validate(2014, 1),
+            Err(InputError::InvalidDate { .. })
+        ));
+        assert!(validate(2024, 0).is_err());
+        assert!(validate(2024, 26).is_err());
+    }
+
+    #[tokio::test]
+    async fn http_store() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2022/day/1/input"))
+            .and(header("Cookie", "session=abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("1000\n2000\n"))
+            .mount(&server)
+            .await;
+
+        let store = HttpStore::new(server.uri(), Some("session=abc".to_owned()));
+        assert_eq!(store.get(2022, 1).await.unwrap(), "1000\n2000\n");
+        assert!(matches!(
+            store.get(2022, 2).await,
+            Err(InputError::NotFound { .. })
+        ));
+
+        let store = HttpStore::new(server.uri(), None);
+        assert!(matches!(
+            store.get(2022, 1).await,
+            Err(InputError::MissingCookie)
+        ));
+    }
+
+    #[tokio::test]
+    async fn age_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let store = AgeStore::new(dir.path(), Some(identity.clone()), identity.to_public());
+
+        store.put(2020, 3, "..##.......\n").await.unwrap();
+        let encrypted = std::fs::read(dir.path().join("2020/day3.txt.age")).unwrap();
+        assert!(!encrypted.windows(4).any(|w| w == b"..##"));
+        assert_eq!(store.get(2020, 3).await.unwrap(), "..##.......\n");
+        assert!(matches!(
+            store.get(2020, 4).await,
+            Err(InputError::NotFound { .. })
+        ));
+
+        let locked = AgeStore::new(dir.path(), None, identity.to_public());
+        assert!(matches!(
+            locked.get(2020, 3).await,
+            Err(InputError::MissingKey)
+        ));
+
+        let other = age::x25519::Identity::generate();
+        let wrong_key = AgeStore::new(dir.path(), Some(other), identity.to_public());
+        assert!(matches!(
+            wrong_key.get(2020, 3).await,
+            Err(InputError::Decrypt(_))
+        ));
+    }
+
+    #[test]
+    fn identity_file() {
+        use age::secrecy::ExposeSecret;
+
+        let dir = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let path = dir.path().join(".age-key");
+        std::fs::write(
+            &path,
+            format!(
+                "# created: 2024-12-01T00:00:00Z\n# public key: {}\n{}\n",
+                identity.to_public(),
+                identity.to_string().expose_secret()
+            ),
+        )
+        .unwrap();
+        let read = read_identity(&path).unwrap();
+        assert_eq!(
+            read.to_public().to_string(),
+            identity.to_public().to_string()
+        );
+
+        assert!(matches!(
+            read_identity(dir.path().join("missing")),
+            Err(InputError::MissingKey)
+        ));
+        std::fs::write(&path, "AGE-SECRET-KEY-1NOPE\n").unwrap();
+        assert!(matches!(
+            read_identity(&path),
+            Err(InputError::InvalidKey(_))
+        ));
+        assert!(AGE_PUBLIC_KEY.parse::<age::x25519::Recipient>().is_ok());
+    }
+
+    #[tokio::test]
+    async fn bad_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let recipient = age::x25519::Identity::generate().to_public();
+        AgeStore::new(dir.path(), None, recipient.clone())
+            .put(2019, 1, "12\n")
+            .await
+            .unwrap();
+
+        // A malformed or unreadable key is reported when decrypting, not when loading
+        let path = dir.path().join(".age-key");
+        std::fs::write(&path, "AGE-SECRET-KEY-1NOPE\n").unwrap();
+        let store = AgeStore::with_key_file(dir.path(), &path, recipient.clone());
+        assert!(matches!(
+            store.get(2019, 1).await,
+            Err(InputError::InvalidKey(_))
+        ));
+
+        let store = AgeStore::with_key_file(dir.path(), dir.path(), recipient);
+        assert!(matches!(store.get(2019, 1).await, Err(InputError::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn cached_store() {
+        let store = Cached {
+            cache: MemoryStore::new(),
+            remote: MemoryStore::new().with(2021, 1, "199\n200\n"),
+        };
+        assert_eq!(store.get(2021, 1).await.unwrap(), "199\n200\n");
+        assert_eq!(store.cache.get(2021, 1).await.unwrap(), "199\n200\n");
+        assert!(matches!(
+            store.get(2021, 2).await,
+            Err(InputError::NotFound { .. })
+        ));
+
+        // Without the key an encrypted input is skipped over rather than failing
+        let dir = tempfile::tempdir().unwrap();
+        let recipient = age::x25519::Identity::generate().to_public();
+        let locked = AgeStore::new(dir.path(), None, recipient);
+        locked.put(2021, 1, "199\n").await.unwrap();
+        let encrypted = std::fs::read(locked.path(2021, 1)).unwrap();
+        let store = Cached {
+            cache: locked,
+            remote: MemoryStore::new().with(2021, 1, "199\n"),
+        };
+        assert_eq!(store.get(2021, 1).await.unwrap(), "199\n");
+        assert_eq!(std::fs::read(store.cache.path(2021, 1)).unwrap(), encrypted);
+    }
+}
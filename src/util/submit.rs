@@ -0,0 +1,531 @@
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use super::store::{project_path, HttpStore, InputError, DATA_PATH};
+
+pub const GUESSES_FILE: &str = "guesses.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// How the site responded to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Refusal {
+    #[error("{0} was already submitted and is wrong")]
+    KnownWrong(String),
+    #[error("already solved with {0}")]
+    AlreadySolved(String),
+    #[error("{answer} is outside the known bounds {}..{}", fmt_bound(.low), fmt_bound(.high))]
+    OutOfBounds {
+        answer: i64,
+        low: Option<i64>,
+        high: Option<i64>,
+    },
+    #[error("submitting too soon, wait {0:?}")]
+    RateLimited(Duration),
+}
+
+fn fmt_bound(bound: &Option<i64>) -> String {
+    bound.map(|b| b.to_string()).unwrap_or_default()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubmitError {
+    #[error(transparent)]
+    Refused(#[from] Refusal),
+    #[error("unrecognised response: {0}")]
+    UnknownResponse(String),
+    #[error("{path}:{line}: malformed guess")]
+    Malformed { path: PathBuf, line: usize },
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl From<reqwest::Error> for SubmitError {
+    fn from(e: reqwest::Error) -> Self {
+        SubmitError::Input(e.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "high",
+            Outcome::TooLow => "low",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "wrong" => Some(Outcome::Wrong),
+            "high" => Some(Outcome::TooHigh),
+            "low" => Some(Outcome::TooLow),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+/// Every answer submitted so far, and when the next submission is allowed.
+///
+/// Stored next to the inputs as lines of `year day part outcome answer`, and `wait unix_secs`.
+#[derive(Debug, Default)]
+pub struct Guesses {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+    wait_until: Option<SystemTime>,
+}
+
+impl Guesses {
+    pub fn load() -> Result<Guesses, SubmitError> {
+        Guesses::load_from(project_path(DATA_PATH).join(GUESSES_FILE))
+    }
+
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Guesses, SubmitError> {
+        let path = path.into();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut guesses = Guesses {
+            path,
+            ..Default::default()
+        };
+        for (i, line) in text.lines().enumerate() {
+            let malformed = || SubmitError::Malformed {
+                path: guesses.path.clone(),
+                line: i + 1,
+            };
+
+            if let Some(secs) = line.strip_prefix("wait ") {
+                let secs = secs.parse().map_err(|_| malformed())?;
+                guesses.wait_until = Some(UNIX_EPOCH + Duration::from_secs(secs));
+                continue;
+            }
+
+            let mut fields = line.splitn(5, ' ');
+            let mut number = || fields.next().and_then(|f| f.parse().ok());
+            let (Some(year), Some(day), Some(part)) = (number(), number(), number()) else {
+                return Err(malformed());
+            };
+            let outcome = fields
+                .next()
+                .and_then(Outcome::from_name)
+                .ok_or_else(malformed)?;
+            let answer = fields.next().ok_or_else(malformed)?.to_owned();
+            guesses.guesses.push(Guess {
+                year,
+                day,
+                part,
+                outcome,
+                answer,
+            });
+        }
+
+        Ok(guesses)
+    }
+
+    pub fn save(&self) -> Result<(), SubmitError> {
+        let mut text = String::new();
+        for guess in &self.guesses {
+            text.push_str(&format!(
+                "{} {} {} {} {}\n",
+                guess.year,
+                guess.day,
+                guess.part,
+                guess.outcome.name(),
+                guess.answer
+            ));
+        }
+        if let Some(wait_until) = self.wait_until {
+            let secs = wait_until.duration_since(UNIX_EPOCH).unwrap().as_secs();
+            text.push_str(&format!("wait {secs}\n"));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, text)?;
+        Ok(())
+    }
+
+    pub fn for_part(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.year == year && g.day == day && g.part == part)
+    }
+
+    /// The exclusive bounds learned from too high and too low guesses.
+    pub fn bounds(&self, year: u32, day: u32, part: u32) -> (Option<i64>, Option<i64>) {
+        let (mut low, mut high) = (None, None);
+        for guess in self.for_part(year, day, part) {
+            let Ok(answer) = guess.answer.parse::<i64>() else {
+                continue;
+            };
+            match guess.outcome {
+                Outcome::TooLow => low = low.max(Some(answer)),
+                Outcome::TooHigh => high = Some(high.map_or(answer, |h: i64| h.min(answer))),
+                _ => {}
+            }
+        }
+        (low, high)
+    }
+
+    /// Refuses answers which can't be right, or which would be rate limited anyway.
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<(), Refusal> {
+        for guess in self.for_part(year, day, part) {
+            match guess.outcome {
+                Outcome::Correct => return Err(Refusal::AlreadySolved(guess.answer.clone())),
+                _ if guess.answer == answer => {
+                    return Err(Refusal::KnownWrong(guess.answer.clone()))
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            let (low, high) = self.bounds(year, day, part);
+            if low.is_some_and(|low| value <= low) || high.is_some_and(|high| value >= high) {
+                return Err(Refusal::OutOfBounds {
+                    answer: value,
+                    low,
+                    high,
+                });
+            }
+        }
+
+        if let Some(wait) = self
+            .wait_until
+            .and_then(|until| until.duration_since(SystemTime::now()).ok())
+        {
+            return Err(Refusal::RateLimited(wait));
+        }
+
+        Ok(())
+    }
+
+    /// Records the response to a submitted answer.
+    pub fn record(&mut self, year: u32, day: u32, part: u32, answer: &str, verdict: &Verdict) {
+        let (outcome, wait) = match *verdict {
+            Verdict::Correct => (Some(Outcome::Correct), None),
+            Verdict::Wrong { hint, wait } => {
+                let outcome = match hint {
+                    Some(Hint::TooHigh) => Outcome::TooHigh,
+                    Some(Hint::TooLow) => Outcome::TooLow,
+                    None => Outcome::Wrong,
+                };
+                (Some(outcome), wait)
+            }
+            Verdict::TooSoon { wait } => (None, Some(wait)),
+            Verdict::WrongLevel => (None, None),
+        };
+
+        if let Some(outcome) = outcome {
+            self.guesses.push(Guess {
+                year,
+                day,
+                part,
+                outcome,
+                answer: answer.to_owned(),
+            });
+        }
+        self.wait_until = wait.map(|wait| SystemTime::now() + wait);
+    }
+}
+
+/// Reads the verdict out of the `<article>` in the response page.
+pub fn parse_response(html: &str) -> Result<Verdict, SubmitError> {
+    static ARTICLE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+    static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+    static LEFT_TO_WAIT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+    static WAIT_MINUTES: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"wait (one|\d+) minutes? before trying").unwrap());
+
+    let article = ARTICLE
+        .captures(html)
+        .map_or(html, |c| c.get(1).unwrap().as_str());
+    let text = TAG.replace_all(article, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = WAIT_MINUTES.captures(&text).map(|c| {
+            let minutes = match &c[1] {
+                "one" => 1,
+                n => n.parse().unwrap(),
+            };
+            Duration::from_secs(minutes * 60)
+        });
+        Ok(Verdict::Wrong { hint, wait })
+    } else if let Some(c) = LEFT_TO_WAIT.captures(&text) {
+        let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = c[2].parse().unwrap();
+        Ok(Verdict::TooSoon {
+            wait: Duration::from_secs(minutes * 60 + seconds),
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(SubmitError::UnknownResponse(text))
+    }
+}
+
+/// Submits an answer unless the guess history rules it out, then records the response.
+pub async fn submit(
+    http: &HttpStore,
+    guesses: &mut Guesses,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    guesses.check(year, day, part, answer)?;
+
+    let res = http
+        .request(reqwest::Method::POST, &format!("/{year}/day/{day}/answer"))?
+        .form(&[("level", part.to_string()), ("answer", answer.to_owned())])
+        .send()
+        .await?;
+    let status = res.status();
+    if !status.is_success() {
+        return Err(InputError::Status(status).into());
+    }
+
+    let verdict = parse_response(&res.text().await?)?;
+    guesses.record(year, day, part, answer, &verdict);
+    guesses.save()?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_string, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+    }
+
+    const RIGHT: &str = "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the chief.";
+    const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+    const TOO_LOW: &str = "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.";
+    const TOO_SOON: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+    const WRONG_LEVEL: &str = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(parse_response(&page(RIGHT)).unwrap(), Verdict::Correct);
+        assert_eq!(
+            parse_response(&page(TOO_HIGH)).unwrap(),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(TOO_LOW)).unwrap(),
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(TOO_SOON)).unwrap(),
+            Verdict::TooSoon {
+                wait: Duration::from_secs(65)
+            }
+        );
+        assert_eq!(
+            parse_response(&page(WRONG_LEVEL)).unwrap(),
+            Verdict::WrongLevel
+        );
+        assert!(matches!(
+            parse_response(&page("Something else")),
+            Err(SubmitError::UnknownResponse(_))
+        ));
+    }
+
+    #[test]
+    fn refuse_known_guesses() {
+        let mut guesses = Guesses::default();
+        let wrong = |hint| Verdict::Wrong { hint, wait: None };
+        guesses.record(2024, 1, 1, "100", &wrong(Some(Hint::TooHigh)));
+        guesses.record(2024, 1, 1, "20", &wrong(Some(Hint::TooLow)));
+        guesses.record(2024, 1, 1, "50", &wrong(None));
+        guesses.record(2024, 1, 1, "80", &wrong(Some(Hint::TooHigh)));
+
+        assert_eq!(guesses.bounds(2024, 1, 1), (Some(20), Some(80)));
+        assert_eq!(
+            guesses.check(2024, 1, 1, "50"),
+            Err(Refusal::KnownWrong("50".to_owned()))
+        );
+        assert_eq!(
+            guesses.check(2024, 1, 1, "90"),
+            Err(Refusal::OutOfBounds {
+                answer: 90,
+                low: Some(20),
+                high: Some(80)
+            })
+        );
+        assert!(guesses.check(2024, 1, 1, "20").is_err());
+        assert_eq!(guesses.check(2024, 1, 1, "60"), Ok(()));
+        assert_eq!(guesses.check(2024, 1, 2, "90"), Ok(()));
+
+        guesses.record(2024, 1, 1, "60", &Verdict::Correct);
+        assert_eq!(
+            guesses.check(2024, 1, 1, "61"),
+            Err(Refusal::AlreadySolved("60".to_owned()))
+        );
+
+        guesses.record(
+            2024,
+            1,
+            2,
+            "1",
+            &Verdict::TooSoon {
+                wait: Duration::from_secs(30),
+            },
+        );
+        assert!(matches!(
+            guesses.check(2024, 1, 2, "1"),
+            Err(Refusal::RateLimited(_))
+        ));
+    }
+
+    #[test]
+    fn guesses_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(GUESSES_FILE);
+        let mut guesses = Guesses::load_from(&path).unwrap();
+        guesses.record(
+            2023,
+            5,
+            2,
+            "123",
+            &Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(60)),
+            },
+        );
+        guesses.record(2023, 6, 1, "ABC DEF", &Verdict::Correct);
+        guesses.save().unwrap();
+
+        let loaded = Guesses::load_from(&path).unwrap();
+        assert_eq!(loaded.guesses, guesses.guesses);
+        assert_eq!(loaded.bounds(2023, 5, 2), (Some(123), None));
+        assert!(loaded.wait_until.is_none());
+
+        std::fs::write(&path, "2023 5 2 maybe 1\n").unwrap();
+        assert!(matches!(
+            Guesses::load_from(&path),
+            Err(SubmitError::Malformed { line: 1, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn submit_answers() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/2024/day/1/answer"))
+            .and(body_string("level=1&answer=11"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page(TOO_HIGH)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/2024/day/1/answer"))
+            .and(body_string("level=2&answer=31"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page(RIGHT)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let http = HttpStore::new(server.uri(), Some("session=abc".to_owned()));
+        let mut guesses = Guesses::load_from(dir.path().join(GUESSES_FILE)).unwrap();
+
+        let verdict = submit(&http, &mut guesses, 2024, 1, 1, "11").await.unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                ..
+            }
+        ));
+
+        // Refused locally without reaching the server
+        assert!(matches!(
+            submit(&http, &mut guesses, 2024, 1, 1, "12").await,
+            Err(SubmitError::Refused(Refusal::OutOfBounds { .. }))
+        ));
+        assert!(matches!(
+            submit(&http, &mut guesses, 2024, 1, 1, "5").await,
+            Err(SubmitError::Refused(Refusal::RateLimited(_)))
+        ));
+
+        guesses.wait_until = None;
+        let verdict = submit(&http, &mut guesses, 2024, 1, 2, "31").await.unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let saved = Guesses::load_from(dir.path().join(GUESSES_FILE)).unwrap();
+        assert_eq!(saved.for_part(2024, 1, 1).count(), 1);
+        assert_eq!(saved.for_part(2024, 1, 2).count(), 1);
+    }
+}